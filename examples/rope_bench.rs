@@ -0,0 +1,110 @@
+//! Compares the day9 knot-following rules on long ropes and large inputs.
+//!
+//! Run with `cargo run --release --example rope_bench [MOVES]`.
+
+use std::collections::HashSet;
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_2022::days::day9::{run_simulation, tail_positions};
+use aoc_2022::point::{Direction, Point, DIAGONALS};
+
+/// The original rule `day9::follow` replaced: try every neighbouring step for
+/// one next to `target` in a straight line, otherwise one diagonally adjacent
+/// to it. Knots are never more than two cells apart, so one of them is.
+fn follow_by_search(current: Point, target: Point) -> Point {
+    if current.chebyshev(target) <= 1 {
+        return current;
+    }
+    let steps = || {
+        Direction::ALL
+            .iter()
+            .map(|d| d.offset())
+            .chain(DIAGONALS)
+            .map(|d| current + d)
+    };
+    steps()
+        .find(|&x| x.manhattan(target) == 1)
+        .or_else(|| steps().find(|&x| x.manhattan(target) == 2))
+        .expect("knots are at most two cells from the one they follow")
+}
+
+/// The simulation as it was before, moving every knot with `follow_by_search`.
+fn simulate_by_search(moves: &[(Point, i32)], rope_size: usize) -> usize {
+    let mut rope = vec![Point::ORIGIN; rope_size];
+    let mut seen = HashSet::from([Point::ORIGIN]);
+    for &(offset, step) in moves {
+        for _ in 0..step {
+            rope[0] += offset;
+            for i in 1..rope_size {
+                rope[i] = follow_by_search(rope[i], rope[i - 1]);
+            }
+            seen.insert(rope[rope_size - 1]);
+        }
+    }
+    seen.len()
+}
+
+/// Pseudo-random moves from a fixed seed, so every run times the same input.
+fn moves(count: usize) -> Vec<(Point, i32)> {
+    let mut state: u64 = 0x2022_0009;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+    (0..count)
+        .map(|_| {
+            (
+                Direction::ALL[next() % 4].offset(),
+                (next() % 20 + 1) as i32,
+            )
+        })
+        .collect()
+}
+
+fn time(mut f: impl FnMut() -> usize) -> (usize, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+fn main() {
+    let count = env::args()
+        .nth(1)
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(20_000);
+    let moves = moves(count);
+
+    println!("{} moves", count);
+    println!(
+        "{:>6}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "knots", "search", "signum", "array", "speed-up"
+    );
+    for knots in [2, 10, 100, 1000] {
+        let (expected, old) = time(|| simulate_by_search(&moves, knots));
+        let (visited, new) = time(|| run_simulation(&moves, knots));
+        assert_eq!(visited, expected);
+
+        let array = match knots {
+            2 => Some(time(|| tail_positions::<2>(&moves))),
+            10 => Some(time(|| tail_positions::<10>(&moves))),
+            _ => None,
+        };
+        if let Some((visited, _)) = array {
+            assert_eq!(visited, expected);
+        }
+        let fastest = array.map_or(new, |(_, t)| t.min(new));
+
+        println!(
+            "{:>6}  {:>12}  {:>12}  {:>12}  {:>7.1}x",
+            knots,
+            format!("{:.2?}", old),
+            format!("{:.2?}", new),
+            array.map_or("-".to_owned(), |(_, t)| format!("{:.2?}", t)),
+            old.as_secs_f64() / fastest.as_secs_f64()
+        );
+    }
+}
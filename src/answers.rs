@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::days;
+use crate::error::{Error, Result};
+use crate::input::Inputs;
+use crate::solution::{Answer, Part};
+
+/// Confirmed answers, keyed by day and then part:
+/// `{"1": {"1": "69528", "2": "206152"}, ...}`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<u8, BTreeMap<u8, String>>);
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("cannot read {}: {}", path.display(), e)))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| Error::new(format!("invalid answers {}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::new(format!("cannot serialize answers: {}", e)))?;
+        contents.push('\n');
+        fs::write(path, contents)
+            .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&day)?.get(&part.number()).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.0
+            .entry(day)
+            .or_default()
+            .insert(part.number(), answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Status {
+        match self.get(day, part) {
+            None => Status::Unknown,
+            Some(expected) if expected == answer.to_string() => Status::Pass,
+            Some(expected) => Status::Fail(expected.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// The answer didn't match the recorded one, which is kept here.
+    Fail(String),
+    Unknown,
+    /// The day couldn't be loaded, parsed or solved; counts as a failure.
+    Error(Error),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail(_) => write!(f, "FAIL"),
+            Self::Unknown => write!(f, "unknown"),
+            Self::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    /// The answer, unless the status is [`Status::Error`].
+    pub answer: Option<Answer>,
+    pub status: Status,
+}
+
+/// Solves every selected day and compares the results with `answers`. A day
+/// that fails to load, parse or solve gets [`Status::Error`] checks and the
+/// other days are still verified.
+pub fn verify(selected: &[u8], inputs: &Inputs, answers: &Answers) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    for &number in selected {
+        let day = days::get(number).ok_or_else(|| Error::new("not implemented").for_day(number))?;
+        let solution = inputs.load(day).and_then(|input| day.parse(&input));
+        for part in Part::ALL {
+            let answer = solution
+                .as_ref()
+                .map_err(Error::clone)
+                .and_then(|solution| day.solve(solution.as_ref(), part))
+                .map_err(|e| e.for_day(number));
+            let (answer, status) = match answer {
+                Ok(answer) => {
+                    let status = answers.check(number, part, &answer);
+                    (Some(answer), status)
+                }
+                Err(e) => (None, Status::Error(e)),
+            };
+            checks.push(Check {
+                day: number,
+                part,
+                answer,
+                status,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{verify, Answers, Status};
+    use crate::input::Inputs;
+    use crate::solution::Part;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn verify_against_answers() {
+        let dir = TempDir::new("answers");
+        fs::write(dir.join("day6.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+
+        let path = dir.join("answers.json");
+        let mut answers = Answers::load(&path).unwrap();
+        answers.record(6, Part::One, &7.into());
+        answers.record(6, Part::Two, &20.into());
+        answers.save(&path).unwrap();
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(6, Part::One), Some("7"));
+
+        answers.record(6, Part::Two, &21.into());
+        let checks = verify(&[6], &Inputs::new(&*dir), &answers).unwrap();
+        assert_eq!(checks[0].status, Status::Pass);
+        assert_eq!(checks[1].status, Status::Fail("21".to_owned()));
+        let answer = checks[0].answer.as_ref().unwrap();
+        assert_eq!(
+            Answers::default().check(6, Part::One, answer),
+            Status::Unknown
+        );
+    }
+
+    #[test]
+    fn verify_reports_broken_days() {
+        let dir = TempDir::new("answers_broken");
+        fs::write(dir.join("day4.txt"), "2-4,6-8\nnot a pair\n").unwrap();
+        fs::write(dir.join("day6.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+
+        let mut answers = Answers::default();
+        answers.record(6, Part::One, &7.into());
+        let checks = verify(&[4, 6], &Inputs::new(&*dir), &answers).unwrap();
+        assert_eq!(checks.len(), 4);
+        for check in &checks[..2] {
+            assert!(check.answer.is_none());
+            assert!(
+                matches!(&check.status, Status::Error(e) if e.day() == Some(4) && e.line() == Some(2))
+            );
+        }
+        assert_eq!(checks[2].status, Status::Pass);
+        assert_eq!(checks[3].status, Status::Unknown);
+    }
+}
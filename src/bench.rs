@@ -0,0 +1,152 @@
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::days;
+use crate::error::{Error, Result};
+use crate::input::Inputs;
+use crate::solution::Part;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Self::Part1,
+            Part::Two => Self::Part2,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part1"),
+            Self::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Summary of the samples taken for one phase; durations serialize as nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "nanos")]
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        Some(Self {
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        })
+    }
+}
+
+/// Serializes a duration as whole nanoseconds, for `serialize_with`.
+pub(crate) fn nanos<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+fn measure<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = black_box(f()?);
+        samples.push(start.elapsed());
+        // Dropping can be costly (a parsed day's maps and trees), so it
+        // happens after the clock stops.
+        drop(result);
+    }
+    Stats::from_samples(&mut samples)
+        .ok_or_else(|| Error::new("at least one iteration is required"))
+}
+
+/// Times parsing and both parts of every selected day, `iterations` times each.
+pub fn bench(selected: &[u8], inputs: &Inputs, iterations: usize) -> Result<Vec<Timing>> {
+    let mut timings = Vec::new();
+    for &number in selected {
+        let day = days::get(number).ok_or_else(|| Error::new("not implemented").for_day(number))?;
+        let input = inputs.load(day)?;
+
+        let stats = measure(iterations, || day.parse(black_box(&input)))?;
+        timings.push(Timing {
+            day: number,
+            phase: Phase::Parse,
+            stats,
+        });
+
+        let solution = day.parse(&input)?;
+        for part in Part::ALL {
+            let stats = measure(iterations, || day.solve(solution.as_ref(), part))?;
+            timings.push(Timing {
+                day: number,
+                phase: part.into(),
+                stats,
+            });
+        }
+    }
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    fn stats() {
+        let mut samples = [5, 1, 3, 7].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+        assert_eq!(Stats::from_samples(&mut []), None);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(
+            json,
+            r#"{"iterations":4,"min_ns":1000000,"median_ns":4000000,"mean_ns":4000000}"#
+        );
+    }
+}
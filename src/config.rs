@@ -0,0 +1,75 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+pub const CONFIG_FILE: &str = "aoc.json";
+pub const DEFAULT_INPUTS_DIR: &str = "src/inputs";
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.json";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings read from `aoc.json` (or the file named by `AOC_CONFIG`), with
+/// `AOC_*` environment variables taking precedence over the file.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub inputs_dir: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    /// Session cookie used to download inputs.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(CONFIG_FILE));
+
+        let mut config = if path.exists() {
+            Self::from_file(&path)?
+        } else {
+            Self::default()
+        };
+
+        if let Some(dir) = env::var_os("AOC_INPUTS_DIR") {
+            config.inputs_dir = Some(dir.into());
+        }
+        if let Some(path) = env::var_os("AOC_ANSWERS") {
+            config.answers = Some(path.into());
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(url);
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("cannot read config {}: {}", path.display(), e)))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| Error::new(format!("invalid config {}: {}", path.display(), e)))
+    }
+
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.inputs_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR))
+    }
+
+    pub fn answers(&self) -> PathBuf {
+        self.answers
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+}
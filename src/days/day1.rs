@@ -0,0 +1,72 @@
+use std::collections::BinaryHeap;
+
+use crate::error::{parse_number, Error, Result};
+use crate::solution::{Answer, Solution};
+
+fn proc_input(input: &str) -> Result<Vec<u32>> {
+    let data = input
+        .split("\n\n")
+        .map(|elf_data| {
+            elf_data
+                .lines()
+                .filter(|v| !v.is_empty())
+                .map(parse_number::<u32>)
+                .sum()
+        })
+        .collect::<Result<BinaryHeap<u32>>>()?
+        .into_sorted_vec();
+
+    Ok(data.into_iter().rev().take(3).collect())
+}
+
+pub struct Day1 {
+    top3: Vec<u32>,
+}
+
+impl Solution for Day1 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            top3: proc_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let most = self
+            .top3
+            .first()
+            .ok_or_else(|| Error::new("no elves in input"))?;
+        Ok((*most).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.top3.iter().sum::<u32>().into())
+    }
+}
+
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+    const TEST_INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"#;
+    #[test]
+    fn part1_ok() {
+        assert_eq!(Day1::parse(TEST_INPUT).unwrap().part1(), Ok(24000.into()));
+    }
+
+    #[test]
+    fn part2_ok() {
+        assert_eq!(Day1::parse(TEST_INPUT).unwrap().part2(), Ok(45000.into()));
+    }
+}
@@ -0,0 +1,225 @@
+use crate::error::{parse_number, Error, Result};
+use crate::solution::{Answer, Solution};
+
+const WIDTH: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    /// How many cycles the instruction takes to complete.
+    pub const fn cycles(self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+}
+
+pub fn parse_instruction(l: &str) -> Result<Instruction> {
+    let l = l.trim_end_matches('\r');
+    match l.split_once(' ') {
+        Some(("addx", number)) => Ok(Instruction::Addx(parse_number(number)?)),
+        None if l == "noop" => Ok(Instruction::Noop),
+        _ => Err(Error::invalid(l, "unknown instruction")),
+    }
+}
+
+type Observer<'a> = Box<dyn FnMut(usize, i32) + 'a>;
+
+/// Runs a program one clock cycle at a time. Each step yields the cycle
+/// number, counting from 1, and the value of `X` during that cycle; an
+/// `addx` only changes `X` once its second cycle has finished.
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    pc: usize,
+    // Cycles left before the instruction at `pc` completes.
+    remaining: usize,
+    cycle: usize,
+    x: i32,
+    observers: Vec<Observer<'a>>,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Self {
+            program,
+            pc: 0,
+            remaining: program.first().map_or(0, |i| i.cycles()),
+            cycle: 0,
+            x: 1,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Calls `observer` with the cycle number and `X` during every cycle.
+    pub fn with_observer(mut self, observer: impl FnMut(usize, i32) + 'a) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// The number of cycles completed so far.
+    pub fn elapsed(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// Runs one cycle, or returns `None` once the program has finished.
+    pub fn step(&mut self) -> Option<(usize, i32)> {
+        let instruction = *self.program.get(self.pc)?;
+        self.cycle += 1;
+        let during = (self.cycle, self.x);
+        for observer in &mut self.observers {
+            observer(self.cycle, self.x);
+        }
+
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            if let Instruction::Addx(n) = instruction {
+                self.x += n;
+            }
+            self.pc += 1;
+            self.remaining = self.program.get(self.pc).map_or(0, |i| i.cycles());
+        }
+        Some(during)
+    }
+
+    /// Runs the program to the end and returns the final value of `X`.
+    pub fn run(mut self) -> i32 {
+        while self.step().is_some() {}
+        self.x
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = (usize, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
+}
+
+fn part1(program: &[Instruction]) -> i32 {
+    Cpu::new(program)
+        .filter(|&(cycle, _)| cycle % WIDTH == 20)
+        .map(|(cycle, x)| cycle as i32 * x)
+        .sum()
+}
+
+/// The CRT draws the pixel for each cycle, lit if the three-pixel-wide
+/// sprite centred on `X` covers it.
+fn part2(program: &[Instruction]) -> String {
+    let mut pixels = String::with_capacity((WIDTH + 1) * 6);
+    Cpu::new(program)
+        .with_observer(|cycle, x| {
+            let pos = ((cycle - 1) % WIDTH) as i32;
+            pixels.push(if (x - 1..=x + 1).contains(&pos) {
+                '#'
+            } else {
+                '.'
+            });
+            if cycle.is_multiple_of(WIDTH) {
+                pixels.push('\n');
+            }
+        })
+        .run();
+    pixels
+}
+
+pub struct Day10 {
+    instructions: Vec<Instruction>,
+}
+
+impl Day10 {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            instructions: input
+                .lines()
+                .map(parse_instruction)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.instructions).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.instructions).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_instruction, Cpu, Day10, Instruction};
+    use crate::solution::Solution;
+
+    const INPUT: &str = include_str!("../inputs/day10_example.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day10::parse(INPUT).unwrap().part1(), Ok(13140.into()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day10::parse(INPUT).unwrap().part2(),
+            Ok("##..##..##..##..##..##..##..##..##..##..\n\
+            ###...###...###...###...###...###...###.\n\
+            ####....####....####....####....####....\n\
+            #####.....#####.....#####.....#####.....\n\
+            ######......######......######......####\n\
+            #######.......#######.......#######.....\n"
+                .into()),
+        );
+    }
+
+    #[test]
+    fn cpu_cycles() {
+        let program = Day10::parse("noop\naddx 3\naddx -5").unwrap();
+        let program = program.instructions();
+        assert_eq!(
+            program,
+            [
+                Instruction::Noop,
+                Instruction::Addx(3),
+                Instruction::Addx(-5)
+            ]
+        );
+        let cycles: Vec<_> = Cpu::new(program).collect();
+        assert_eq!(cycles, [(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+
+        let mut cpu = Cpu::new(program);
+        assert_eq!(cpu.step(), Some((1, 1)));
+        assert_eq!((cpu.elapsed(), cpu.x()), (1, 1));
+        cpu.nth(1);
+        assert_eq!((cpu.elapsed(), cpu.x()), (3, 4));
+
+        let mut seen = Vec::new();
+        let x = Cpu::new(program)
+            .with_observer(|cycle, x| seen.push((cycle, x)))
+            .run();
+        assert_eq!(x, -1);
+        assert_eq!(seen, cycles);
+
+        assert_eq!(Cpu::new(&[]).run(), 1);
+        assert!(parse_instruction("addx").is_err());
+        assert!(parse_instruction("noop 1").is_err());
+        let input = "noop\naddx";
+        let err = Day10::parse(input).err().unwrap().locate(input);
+        assert_eq!(err.line(), Some(2));
+    }
+}
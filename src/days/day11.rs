@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_number, split_once, Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub fn simulate(monkeys: &[Monkey], reduce_worry: usize, rounds: usize) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    let mut counts = vec![0; monkeys.len()];
+    let modulus = monkeys.iter().map(|m| m.test).product();
+    for _ in 0..rounds {
+        let map = simulate_round(&mut monkeys, reduce_worry, modulus);
+        for (idx, v) in map.iter().enumerate() {
+            counts[idx] += v;
+        }
+    }
+    counts.sort();
+
+    counts.iter().rev().take(2).product()
+}
+
+fn simulate_round(monkeys: &mut [Monkey], reduce_worry: usize, modulus: usize) -> Vec<usize> {
+    let mut counts = vec![0; monkeys.len()];
+    for i in 0..monkeys.len() {
+        let monkey = &mut monkeys[i];
+        let mut map: HashMap<usize, Vec<usize>> = HashMap::new();
+        for item in &monkey.items {
+            let worry_level = monkey.op.apply(*item) / reduce_worry;
+
+            let is_divisible = worry_level.is_multiple_of(monkey.test);
+            let idx = if is_divisible {
+                monkey.true_target
+            } else {
+                monkey.false_target
+            };
+            map.entry(idx)
+                .and_modify(|f| f.push(worry_level % modulus))
+                .or_insert_with(|| vec![worry_level % modulus]);
+        }
+        counts[i] += monkey.items.len();
+
+        monkey.items.clear();
+
+        for (idx, items) in map.iter_mut() {
+            monkeys[*idx].items.append(items);
+        }
+    }
+    counts
+}
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self> {
+        let monkeys: Vec<Monkey> = input
+            .split("\n\n")
+            .map(Monkey::parse)
+            .collect::<Result<_>>()?;
+
+        for (idx, monkey) in monkeys.iter().enumerate() {
+            let target = monkey.true_target.max(monkey.false_target);
+            if target >= monkeys.len() {
+                return Err(Error::new(format!(
+                    "monkey {} throws to unknown monkey {}",
+                    idx, target
+                )));
+            }
+        }
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(simulate(&self.monkeys, 3, 20).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(simulate(&self.monkeys, 1, 10000).into())
+    }
+}
+
+#[derive(Clone)]
+pub enum Operation {
+    Add(usize),
+    Mult(usize),
+    Square,
+}
+
+impl Operation {
+    pub fn apply(&self, old: usize) -> usize {
+        match self {
+            Self::Add(v) => old + v,
+            Self::Mult(v) => old * v,
+            Self::Square => old * old,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    op: Operation,
+    test: usize,
+    true_target: usize,
+    false_target: usize,
+}
+impl Monkey {
+    pub fn items(&self) -> &[usize] {
+        &self.items
+    }
+
+    pub fn operation(&self) -> &Operation {
+        &self.op
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let header = lines.next().unwrap_or(s);
+        if !header.starts_with("Monkey ") {
+            return Err(Error::invalid(header, "expected \"Monkey <n>:\""));
+        }
+
+        let mut next_trim_start_matches = |matching: &str| -> Result<&str> {
+            let line = lines
+                .next()
+                .ok_or_else(|| Error::invalid(header, "incomplete monkey"))?;
+            line.strip_prefix(matching)
+                .ok_or_else(|| Error::invalid(line, format!("expected {:?}", matching.trim())))
+        };
+
+        let items = next_trim_start_matches("  Starting items: ")?
+            .split(", ")
+            .map(parse_number::<usize>)
+            .collect::<Result<_>>()?;
+
+        let (op, val) = split_once(next_trim_start_matches("  Operation: new = old ")?, " ")?;
+        let op = match op {
+            "*" if val == "old" => Operation::Square,
+            "*" => Operation::Mult(parse_number(val)?),
+            "+" => Operation::Add(parse_number(val)?),
+            _ => return Err(Error::invalid(op, "invalid operation")),
+        };
+        let test_value = next_trim_start_matches("  Test: divisible by ")?;
+        let test = parse_number::<usize>(test_value)?;
+        if test == 0 {
+            return Err(Error::invalid(test_value, "divisor must be positive"));
+        }
+        let t = parse_number(next_trim_start_matches("    If true: throw to monkey ")?)?;
+        let f = parse_number(next_trim_start_matches("    If false: throw to monkey ")?)?;
+
+        Ok(Self {
+            items,
+            op,
+            test,
+            true_target: t,
+            false_target: f,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day11::parse(INPUT).unwrap().part1(), Ok(10605.into()))
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day11::parse(INPUT).unwrap().part2(),
+            Ok(2713310158u64.into())
+        )
+    }
+
+    #[test]
+    fn invalid_input() {
+        let input = INPUT.replace("old + 6", "old / 6");
+        let err = Day11::parse(&input).err().unwrap().locate(&input);
+        assert_eq!((err.line(), err.column()), (Some(10), Some(24)));
+        assert_eq!(err.text(), Some("/"));
+    }
+}
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::num::Saturating;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::render::{self, Rgb, Style};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+pub struct Day12 {
+    map: HeightMap,
+}
+
+impl Day12 {
+    pub fn map(&self) -> &HeightMap {
+        &self.map
+    }
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: HeightMap::from_str(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let result =
+            bfs(&self.map, self.map.start()).ok_or_else(|| Error::new("no path from S to E"))?;
+        Ok(result.1.len().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let distances = self.map.distances_to_goal();
+        let shortest = self
+            .map
+            .all_start_positions()
+            .into_iter()
+            .filter_map(|start| distances[start])
+            .min()
+            .ok_or_else(|| Error::new("no path from any lowest point to E"))?;
+        Ok(shortest.into())
+    }
+}
+
+/// Which steps between cells are allowed and what they cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// How many levels higher a step may go.
+    pub max_ascent: u8,
+    /// How many levels lower a step may go.
+    pub max_descent: u8,
+    /// Whether diagonal steps are allowed as well as orthogonal ones.
+    pub diagonals: bool,
+    /// Extra cost of a step per level climbed, on top of 1 per step.
+    pub ascent_cost: u32,
+    /// Extra cost of a step per level descended, on top of 1 per step.
+    pub descent_cost: u32,
+}
+
+impl Rules {
+    /// The puzzle's rules: climb at most one level, drop any amount.
+    pub const PUZZLE: Rules = Rules {
+        max_ascent: 1,
+        max_descent: u8::MAX,
+        diagonals: false,
+        ascent_cost: 0,
+        descent_cost: 0,
+    };
+
+    /// The cost of stepping from height `from` to `to`, if it's allowed. At
+    /// most `1 + 255 * u32::MAX`, so it can't overflow.
+    pub fn step_cost(&self, from: u8, to: u8) -> Option<u64> {
+        if to >= from {
+            let ascent = to - from;
+            (ascent <= self.max_ascent).then(|| 1 + u64::from(self.ascent_cost) * u64::from(ascent))
+        } else {
+            let descent = from - to;
+            (descent <= self.max_descent)
+                .then(|| 1 + u64::from(self.descent_cost) * u64::from(descent))
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: Point,
+    goal: Point,
+}
+
+impl HeightMap {
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn goal(&self) -> Point {
+        self.goal
+    }
+
+    pub fn height(&self, p: Point) -> u8 {
+        self.heights[p]
+    }
+
+    pub fn is_goal(&self, p: Point) -> bool {
+        p == self.goal
+    }
+
+    fn neighbours(&self, p: Point, rules: &Rules) -> Vec<Point> {
+        if rules.diagonals {
+            self.heights.neighbours8(p).collect()
+        } else {
+            self.heights.neighbours4(p).collect()
+        }
+    }
+
+    /// Neighbours that can be stepped to from `p` under `rules`, with the cost of each step.
+    pub fn moves(&self, p: Point, rules: &Rules) -> Vec<(Point, u64)> {
+        let height = self.heights[p];
+        self.neighbours(p, rules)
+            .into_iter()
+            .filter_map(|next| Some((next, rules.step_cost(height, self.heights[next])?)))
+            .collect()
+    }
+
+    /// Neighbours from which `p` can be stepped to under `rules`, with the cost of each step.
+    pub fn moves_into(&self, p: Point, rules: &Rules) -> Vec<(Point, u64)> {
+        let height = self.heights[p];
+        self.neighbours(p, rules)
+            .into_iter()
+            .filter_map(|prev| Some((prev, rules.step_cost(self.heights[prev], height)?)))
+            .collect()
+    }
+
+    /// Neighbours that can be climbed to from `p`: at most one higher.
+    pub fn get_possibilities(&self, p: Point) -> Vec<Point> {
+        self.moves(p, &Rules::PUZZLE)
+            .into_iter()
+            .map(|(next, _)| next)
+            .collect()
+    }
+
+    /// The cheapest route under `rules` from any of `starts` to the goal, with
+    /// its total cost. Both ends are included in the route. The cost saturates
+    /// at `u64::MAX`, which takes over 16 million steps at the highest costs.
+    pub fn cheapest_route(&self, starts: &[Point], rules: &Rules) -> Option<(u64, Vec<Point>)> {
+        let search = search::dijkstra(
+            starts.iter().copied(),
+            |p| {
+                self.moves(p, rules)
+                    .into_iter()
+                    .map(|(next, cost)| (next, Saturating(cost)))
+            },
+            |p| self.is_goal(p),
+        );
+        let goal = search.goal()?;
+        Some((search.distance(goal)?.0, search.path(goal)?))
+    }
+
+    /// The shortest route from S to E, both included.
+    pub fn route(&self) -> Option<Vec<Point>> {
+        let (_, path) = bfs(self, self.start)?;
+        Some([self.start].into_iter().chain(path).collect())
+    }
+
+    fn colours(&self) -> Grid<Rgb> {
+        self.heights
+            .map(|_, &height| render::gradient(height as f64 / 25.0))
+    }
+
+    /// Draws the map as height letters with `route` overlaid as arrows
+    /// pointing along it, like the puzzle text. With [`Style::Ansi`] each
+    /// cell is also coloured by height.
+    pub fn render_route(&self, route: &[Point], style: Style) -> Result<String> {
+        let arrows = route_arrows(route)?;
+        let colours = self.colours();
+        let mut out = String::new();
+        for (p, &height) in self.heights.iter() {
+            let c = if p == self.start {
+                'S'
+            } else if p == self.goal {
+                'E'
+            } else if let Some(&arrow) = arrows.get(&p) {
+                arrow
+            } else {
+                (b'a' + height) as char
+            };
+            match style {
+                Style::Plain => out.push(c),
+                Style::Ansi => {
+                    let bg = colours[p];
+                    let fg = if arrows.contains_key(&p) {
+                        Rgb::RED
+                    } else {
+                        render::contrast(bg)
+                    };
+                    out.push_str(&render::ansi(c, fg, bg));
+                }
+            }
+            if p.x as usize + 1 == self.heights.width() {
+                out.push('\n');
+            }
+        }
+        Ok(out)
+    }
+
+    /// An SVG image of the heights as a colour gradient with `route` drawn on top.
+    pub fn route_svg(&self, route: &[Point], scale: usize) -> String {
+        let mut out = render::svg(&self.colours(), scale);
+        let centre = |c: Point| {
+            let (x, y) = (c.x as usize, c.y as usize);
+            (x * scale + scale / 2, y * scale + scale / 2)
+        };
+        let points: Vec<String> = route
+            .iter()
+            .map(|&c| {
+                let (x, y) = centre(c);
+                format!("{},{}", x, y)
+            })
+            .collect();
+        writeln!(
+            out,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            points.join(" "),
+            Rgb::RED.hex(),
+            (scale / 3).max(1)
+        )
+        .expect("writing to a String cannot fail");
+        for (p, label) in [(self.start, 'S'), (self.goal, 'E')] {
+            let (x, y) = centre(p);
+            writeln!(
+                out,
+                r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                x, y, scale, label
+            )
+            .expect("writing to a String cannot fail");
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// A PPM image of the heights as a colour gradient with `route` in red.
+    /// Like [`render_route`](Self::render_route), points of `route` outside
+    /// the map aren't drawn.
+    pub fn route_ppm(&self, route: &[Point], scale: usize) -> Vec<u8> {
+        let mut colours = self.colours();
+        for &p in route {
+            if let Some(colour) = colours.get_mut(p) {
+                *colour = Rgb::RED;
+            }
+        }
+        render::ppm(&colours, scale)
+    }
+
+    /// Neighbours from which `p` can be climbed to, the reverse of
+    /// [`get_possibilities`](Self::get_possibilities).
+    pub fn climbable_from(&self, p: Point) -> Vec<Point> {
+        self.moves_into(p, &Rules::PUZZLE)
+            .into_iter()
+            .map(|(prev, _)| prev)
+            .collect()
+    }
+
+    /// The fewest steps from every cell to the goal, found with a single
+    /// search backwards from it; `None` where the goal can't be reached.
+    pub fn distances_to_goal(&self) -> Grid<Option<usize>> {
+        let search = search::bfs([self.goal], |p| self.climbable_from(p), |_| false);
+        self.heights.map(|p, _| search.distance(p))
+    }
+
+    pub fn all_start_positions(&self) -> Vec<Point> {
+        let lowest = self.heights[self.start];
+        self.heights
+            .iter()
+            .filter(|(_, &height)| height == lowest)
+            .map(|(p, _)| p)
+            .collect()
+    }
+}
+
+impl FromStr for HeightMap {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut start = None;
+        let mut goal = None;
+
+        let heights = Grid::parse(s, |p, cell| {
+            let (marker, height, name) = match cell {
+                "S" => (&mut start, 0, "start S"),
+                "E" => (&mut goal, 25, "goal E"),
+                _ => {
+                    return match cell.as_bytes() {
+                        &[c @ b'a'..=b'z'] => Ok(c - b'a'),
+                        _ => Err(Error::invalid(cell, "invalid height, expected a-z, S or E")),
+                    }
+                }
+            };
+            if let Some(first) = marker.replace(p) {
+                return Err(Error::invalid(
+                    cell,
+                    format!(
+                        "duplicate {}, first seen at line {}, column {}",
+                        name,
+                        first.y + 1,
+                        first.x + 1
+                    ),
+                ));
+            }
+            Ok(height)
+        })?;
+        if heights.is_empty() {
+            return Err(Error::new("empty height map"));
+        }
+
+        Ok(Self {
+            heights,
+            start: start.ok_or_else(|| Error::new("missing start S"))?,
+            goal: goal.ok_or_else(|| Error::new("missing goal E"))?,
+        })
+    }
+}
+
+pub fn bfs(height_map: &HeightMap, start: Point) -> Option<(Point, Vec<Point>)> {
+    let search = search::bfs(
+        [start],
+        |p| height_map.get_possibilities(p),
+        |p| height_map.is_goal(p),
+    );
+    let goal = search.goal()?;
+    let mut path = search.path(goal)?;
+    path.remove(0);
+    Some((goal, path))
+}
+
+/// For each cell on `route` but the last, the arrow towards the next one:
+/// `^`, `v`, `<` and `>` like the puzzle text, and `↖`, `↗`, `↙` and `↘` for
+/// diagonal steps. Fails if two consecutive cells aren't adjacent.
+pub fn route_arrows(route: &[Point]) -> Result<HashMap<Point, char>> {
+    route
+        .windows(2)
+        .map(|step| {
+            let arrow = match step[1] - step[0] {
+                Point { x: 0, y: -1 } => '^',
+                Point { x: 0, y: 1 } => 'v',
+                Point { x: -1, y: 0 } => '<',
+                Point { x: 1, y: 0 } => '>',
+                Point { x: -1, y: -1 } => '↖',
+                Point { x: 1, y: -1 } => '↗',
+                Point { x: -1, y: 1 } => '↙',
+                Point { x: 1, y: 1 } => '↘',
+                _ => {
+                    return Err(Error::new(format!(
+                        "route steps from {:?} to {:?}, which aren't adjacent",
+                        step[0], step[1]
+                    )))
+                }
+            };
+            Ok((step[0], arrow))
+        })
+        .collect()
+}
+
+/// Lays out a distance field as right-aligned numbers, `.` for unreachable cells.
+pub fn render_distances(distances: &Grid<Option<usize>>) -> String {
+    let width = distances
+        .iter()
+        .filter_map(|(_, d)| *d)
+        .max()
+        .map_or(1, |max| max.to_string().len());
+    let mut out = String::new();
+    for row in distances.rows() {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|d| match d {
+                Some(d) => format!("{:>width$}", d, width = width),
+                None => format!("{:>width$}", ".", width = width),
+            })
+            .collect();
+        out.push_str(&cells.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_distances, route_arrows, Day12, Rules};
+    use crate::point::Point;
+    use crate::render::Style;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day12::parse(INPUT).unwrap().part1(), Ok(31.into()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day12::parse(INPUT).unwrap().part2(), Ok(29.into()));
+    }
+
+    #[test]
+    fn distances_to_goal() {
+        let day = Day12::parse(INPUT).unwrap();
+        let map = day.map();
+        let distances = map.distances_to_goal();
+        assert_eq!(distances[map.start()], Some(31));
+        assert_eq!(distances[map.goal()], Some(0));
+
+        let rendered = render_distances(&distances);
+        assert_eq!(rendered.lines().count(), 5);
+        assert_eq!(rendered.lines().nth(2).unwrap(), "31 28 27 10  1  0  5 18");
+    }
+
+    #[test]
+    fn render_route() {
+        let day = Day12::parse(INPUT).unwrap();
+        let map = day.map();
+        let route = map.route().unwrap();
+        assert_eq!(route.len(), 32);
+        assert_eq!(
+            map.render_route(&route, Style::Plain).unwrap(),
+            "Sabv<<<<
+>vcvv<<^
+avcv>E^^
+a>v>>>^^
+ab>>>>>^
+"
+        );
+        assert!(map
+            .render_route(&route, Style::Ansi)
+            .unwrap()
+            .contains("\x1b[38;2;220;40;40m"));
+        assert!(map.route_svg(&route, 10).ends_with("</svg>\n"));
+        assert!(map.route_ppm(&route, 1).starts_with(b"P6\n8 5\n255\n"));
+
+        let outside = [Point::new(7, 4), Point::new(8, 4), Point::new(8, 5)];
+        assert_eq!(map.route_ppm(&outside, 1), map.route_ppm(&outside[..1], 1));
+    }
+
+    #[test]
+    fn render_diagonal_route() {
+        let day = Day12::parse(INPUT).unwrap();
+        let map = day.map();
+        let rules = Rules {
+            diagonals: true,
+            ..Rules::PUZZLE
+        };
+        let (cost, route) = map.cheapest_route(&[map.start()], &rules).unwrap();
+        assert_eq!(cost, 27);
+        assert_eq!(
+            map.render_route(&route, Style::Plain).unwrap(),
+            "Sabv<<<<
+avcv↘<x^
+avcvzE↖^
+a↘c>>>^^
+ab>>>>>^
+"
+        );
+
+        let gap = [Point::new(0, 0), Point::new(2, 0)];
+        assert!(route_arrows(&gap).is_err());
+        assert!(map.render_route(&gap, Style::Plain).is_err());
+    }
+
+    #[test]
+    fn rules() {
+        let day = Day12::parse(INPUT).unwrap();
+        let map = day.map();
+        let start = [map.start()];
+        let cost = |rules: Rules| map.cheapest_route(&start, &rules).map(|(cost, _)| cost);
+
+        assert_eq!(cost(Rules::PUZZLE), Some(31));
+        assert_eq!(
+            cost(Rules {
+                max_ascent: 25,
+                ..Rules::PUZZLE
+            }),
+            Some(7)
+        );
+        assert_eq!(
+            cost(Rules {
+                diagonals: true,
+                ..Rules::PUZZLE
+            }),
+            Some(27)
+        );
+        assert_eq!(
+            cost(Rules {
+                max_descent: 0,
+                ..Rules::PUZZLE
+            }),
+            Some(31)
+        );
+        assert_eq!(
+            cost(Rules {
+                max_ascent: 0,
+                ..Rules::PUZZLE
+            }),
+            None
+        );
+
+        let (cost, route) = map
+            .cheapest_route(
+                &start,
+                &Rules {
+                    max_ascent: 25,
+                    ascent_cost: 10,
+                    ..Rules::PUZZLE
+                },
+            )
+            .unwrap();
+        assert_eq!(cost, 7 + 10 * 25);
+        assert_eq!(route.first(), Some(&map.start()));
+        assert_eq!(route.last(), Some(&map.goal()));
+
+        let rules = Rules {
+            max_ascent: 25,
+            ascent_cost: u32::MAX,
+            descent_cost: u32::MAX,
+            ..Rules::PUZZLE
+        };
+        assert_eq!(rules.step_cost(0, 25), Some(1 + 25 * u64::from(u32::MAX)));
+        assert_eq!(rules.step_cost(25, 0), Some(1 + 25 * u64::from(u32::MAX)));
+        let (cost, _) = map.cheapest_route(&start, &rules).unwrap();
+        assert_eq!(cost, 7 + 25 * u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = |input: &str| Day12::parse(input).err().unwrap().locate(input);
+
+        let err = error(&INPUT.replace("accszExk", "acc?zExk"));
+        assert_eq!((err.line(), err.column()), (Some(3), Some(4)));
+        assert_eq!(err.text(), Some("?"));
+
+        let err = error(&INPUT.replace("accszExk", "accSzExk"));
+        assert_eq!((err.line(), err.column()), (Some(3), Some(4)));
+        assert_eq!(
+            err.message(),
+            "duplicate start S, first seen at line 1, column 1"
+        );
+
+        let err = error(&INPUT.replace("abcryxxl", "abcryxx"));
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.message(), "expected 8 columns, found 7");
+
+        assert_eq!(error(&INPUT.replace('E', "z")).message(), "missing goal E");
+        assert_eq!(error(&INPUT.replace('S', "a")).message(), "missing start S");
+        assert_eq!(error(&INPUT.replace('b', "B")).column(), Some(3));
+        assert_eq!(error("").message(), "empty height map");
+    }
+
+    #[test]
+    fn crlf_input() {
+        let input = INPUT.replace('\n', "\r\n") + "\r\n";
+        let day = Day12::parse(&input).unwrap();
+        assert_eq!(day.map().heights().width(), 8);
+        assert_eq!(day.part1(), Ok(31.into()));
+        assert!(Day12::parse(&INPUT.replace("abdefghi", "abdefghi\r")).is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::packet;
+use crate::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<Packet<'static>>> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(Packet::from_str)
+        .collect()
+}
+
+pub struct Day13 {
+    packets: Vec<Packet<'static>>,
+}
+
+impl Day13 {
+    pub fn packets(&self) -> &[Packet<'static>] {
+        &self.packets
+    }
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            packets: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self
+            .packets
+            .chunks_exact(2)
+            .enumerate()
+            .filter(|(_, v)| v[0] < v[1])
+            .map(|(i, _)| i + 1)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let dividers = [2, 6].map(|x| packet!([[x]]));
+        let mut packets = self.packets.clone();
+        packets.extend(dividers.clone());
+        packets.sort();
+
+        Ok(packets
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| dividers.contains(p))
+            .map(|(i, _)| i + 1)
+            .product::<usize>()
+            .into())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Packet<'a>(Cow<'a, Value>);
+
+#[macro_export]
+macro_rules! packet {
+    ($($l:tt)+) => {
+        $crate::serde_json::from_value::<$crate::days::day13::Packet>($crate::serde_json::json!($($l)+))
+            .unwrap()
+    };
+}
+
+impl<'a> Packet<'a> {
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+}
+
+impl FromStr for Packet<'static> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_str(s).map_err(|e| {
+            let offset = e.column().saturating_sub(1).min(s.len());
+            let text = s.get(offset..).unwrap_or(s);
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            Error::invalid(text, format!("invalid packet ({})", message))
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum Value {
+    Number(u8),
+    Array(Vec<Value>),
+}
+
+impl<'a> PartialOrd for Packet<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Packet<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(self, other)
+    }
+}
+
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left.0.as_ref(), right.0.as_ref()) {
+        (Value::Number(l), Value::Number(r)) => l.cmp(r),
+        (Value::Array(_), Value::Number(r)) => compare(left, &packet!([r])),
+        (Value::Number(l), Value::Array(_)) => compare(&packet!([l]), right),
+        (Value::Array(l), Value::Array(r)) => {
+            for i in 0..std::cmp::min(l.len(), r.len()) {
+                if l[i] != r[i] {
+                    return compare(&Packet(Cow::Borrowed(&l[i])), &Packet(Cow::Borrowed(&r[i])));
+                }
+            }
+            l.len().cmp(&r.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day13, Packet};
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]"#;
+    const INPUT_FULL: &str = include_str!("../inputs/day13.txt");
+    #[test]
+    fn part1() {
+        assert_eq!(Day13::parse(INPUT).unwrap().part1(), Ok(13.into()));
+        assert_eq!(Day13::parse(INPUT_FULL).unwrap().part1(), Ok(6478.into()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day13::parse(INPUT).unwrap().part2(), Ok(140.into()));
+        assert_eq!(Day13::parse(INPUT_FULL).unwrap().part2(), Ok(21922.into()));
+    }
+
+    #[test]
+    fn packet_from_str() {
+        let left: Packet = "[[1],[2,3,4]]".parse().unwrap();
+        let right: Packet = "[[1],4]".parse().unwrap();
+        assert!(left < right);
+        assert_eq!(left, packet!([[1], [2, 3, 4]]));
+        assert!("[1,".parse::<Packet>().is_err());
+    }
+}
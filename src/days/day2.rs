@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::error::{split_once, Error, Result};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayOption {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl PlayOption {
+    const OPTIONS: [PlayOption; 3] = [PlayOption::Paper, PlayOption::Scissors, PlayOption::Rock];
+    pub fn option_score(&self) -> u8 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+}
+
+impl FromStr for PlayOption {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "A" | "X" => Ok(PlayOption::Rock),
+            "B" | "Y" => Ok(PlayOption::Paper),
+            "C" | "Z" => Ok(PlayOption::Scissors),
+            _ => Err(Error::invalid(s, "invalid option")),
+        }
+    }
+}
+
+impl PartialOrd for PlayOption {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        if let PlayOption::Rock = self {
+            match other {
+                PlayOption::Scissors => Some(Ordering::Greater),
+                PlayOption::Paper => Some(Ordering::Less),
+                _ => unreachable!(),
+            }
+        } else if let PlayOption::Paper = self {
+            match other {
+                PlayOption::Rock => Some(Ordering::Greater),
+                PlayOption::Scissors => Some(Ordering::Less),
+                _ => unreachable!(),
+            }
+        } else {
+            match other {
+                PlayOption::Paper => Some(Ordering::Greater),
+                PlayOption::Rock => Some(Ordering::Less),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Expectation {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl FromStr for Expectation {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(Error::invalid(s, "unknown variant")),
+        }
+    }
+}
+impl Expectation {
+    pub fn get_play(&self, enemy_play: &PlayOption) -> PlayOption {
+        fn find_option<F: Fn(&PlayOption) -> bool>(predicate: F) -> PlayOption {
+            PlayOption::OPTIONS.into_iter().find(predicate).unwrap()
+        }
+
+        match self {
+            Self::Win => find_option(|x| enemy_play.lt(x)),
+            Self::Lose => find_option(|x| enemy_play.gt(x)),
+            Self::Draw => find_option(|x| enemy_play.eq(x)),
+        }
+    }
+}
+
+pub fn calculate_games_result(plays: &[(PlayOption, PlayOption)]) -> u32 {
+    plays.iter().fold(0, |accum, (a, b)| {
+        let result_points = match b.partial_cmp(a).unwrap() {
+            Ordering::Greater => 6,
+            Ordering::Equal => 3,
+            Ordering::Less => 0,
+        };
+
+        accum + result_points + (b.option_score() as u32)
+    })
+}
+
+pub struct Day2 {
+    rounds: Vec<(PlayOption, PlayOption, Expectation)>,
+}
+
+impl Solution for Day2 {
+    fn parse(input: &str) -> Result<Self> {
+        let rounds = input
+            .lines()
+            .map(|s| {
+                let (a, b) = split_once(s, " ")?;
+                Ok((
+                    PlayOption::from_str(a)?,
+                    PlayOption::from_str(b)?,
+                    Expectation::from_str(b)?,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { rounds })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let plays: Vec<(PlayOption, PlayOption)> =
+            self.rounds.iter().map(|(a, b, _)| (*a, *b)).collect();
+        Ok(calculate_games_result(plays.as_slice()).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let plays: Vec<(PlayOption, PlayOption)> = self
+            .rounds
+            .iter()
+            .map(|(enemy_turn, _, expectation)| {
+                let own_turn = expectation.get_play(enemy_turn);
+                (*enemy_turn, own_turn)
+            })
+            .collect();
+        Ok(calculate_games_result(plays.as_slice()).into())
+    }
+}
+
+#[cfg(test)]
+mod day2_tests {
+    use super::*;
+    const INPUT: &str = r#"A Y
+B X
+C Z"#;
+    #[test]
+    fn part1_ok() {
+        assert_eq!(Day2::parse(INPUT).unwrap().part1(), Ok(15.into()));
+    }
+    #[test]
+    fn part2_ok() {
+        assert_eq!(Day2::parse(INPUT).unwrap().part2(), Ok(12.into()))
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+fn priority(c: char) -> u32 {
+    if c.is_ascii_uppercase() {
+        c as u32 - 64 + 26
+    } else {
+        c as u32 - 96
+    }
+}
+
+fn part1(rucksacks: &[String]) -> Result<u32> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(idx, ruck)| {
+            let (a, b) = ruck.split_at(ruck.len() / 2);
+            let a = HashSet::<char>::from_iter(a.chars());
+            let b = HashSet::<char>::from_iter(b.chars());
+            a.intersection(&b)
+                .next()
+                .cloned()
+                .ok_or_else(|| Error::new("no item in both compartments").at_line(idx + 1))
+        })
+        .map(|item| item.map(priority))
+        .sum()
+}
+
+fn part2(rucksacks: &[String]) -> Result<u32> {
+    rucksacks
+        .chunks_exact(3)
+        .enumerate()
+        .map(|(idx, triple)| {
+            triple
+                .iter()
+                .map(|s| -> HashSet<char> { HashSet::from_iter(s.chars()) })
+                .fold(HashSet::<char>::new(), |diff, a| {
+                    if diff.is_empty() {
+                        a
+                    } else {
+                        diff.intersection(&a).cloned().collect()
+                    }
+                })
+                .into_iter()
+                .next()
+                .ok_or_else(|| Error::new("no badge shared by group").at_line(idx * 3 + 1))
+        })
+        .map(|item| item.map(priority))
+        .sum()
+}
+
+pub struct Day3 {
+    rucksacks: Vec<String>,
+}
+
+impl Solution for Day3 {
+    fn parse(input: &str) -> Result<Self> {
+        let rucksacks = input
+            .lines()
+            .map(|l| match l.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(idx) => Err(Error::invalid(&l[idx..], "invalid item")),
+                None => Ok(l.to_owned()),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { rucksacks })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(part1(&self.rucksacks)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(part2(&self.rucksacks)?.into())
+    }
+}
+
+#[cfg(test)]
+mod day3_tests {
+    use super::*;
+    const INPUT: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"#;
+
+    #[test]
+    fn part1_ok() {
+        assert_eq!(Day3::parse(INPUT).unwrap().part1(), Ok(157.into()));
+    }
+
+    #[test]
+    fn part2_ok() {
+        assert_eq!(Day3::parse(INPUT).unwrap().part2(), Ok(70.into()));
+    }
+}
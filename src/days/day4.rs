@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use crate::error::{parse_number, split_once, Error, Result};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct IdRange {
+    from: u32,
+    to: u32,
+}
+
+impl IdRange {
+    pub fn new(from: u32, to: u32) -> Self {
+        Self { from, to }
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        self.from >= other.from && self.to <= other.to
+    }
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.from <= other.to && self.to >= other.from
+    }
+}
+
+impl FromStr for IdRange {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let (from, to) = split_once(s, "-")?;
+        let from = parse_number(from)?;
+        let to = parse_number(to)?;
+        Ok(IdRange { from, to })
+    }
+}
+
+pub struct Day4 {
+    pairs: Vec<(IdRange, IdRange)>,
+}
+
+impl Day4 {
+    pub fn pairs(&self) -> &[(IdRange, IdRange)] {
+        &self.pairs
+    }
+
+    fn count<F: Fn(&&(IdRange, IdRange)) -> bool>(&self, predicate: F) -> usize {
+        self.pairs.iter().filter(predicate).count()
+    }
+}
+
+impl Solution for Day4 {
+    fn parse(input: &str) -> Result<Self> {
+        let pairs = input
+            .lines()
+            .map(|s| {
+                let (a, b) = split_once(s, ",")?;
+                Ok((IdRange::from_str(a)?, IdRange::from_str(b)?))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { pairs })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.count(|(a, b)| a.contains(b) || b.contains(a)).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.count(|(a, b)| a.overlaps(b)).into())
+    }
+}
+
+#[cfg(test)]
+mod day4_tests {
+    use super::Day4;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"#;
+    #[test]
+    fn part1() {
+        assert_eq!(Day4::parse(INPUT).unwrap().part1(), Ok(2.into()))
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day4::parse(INPUT).unwrap().part2(), Ok(4.into()))
+    }
+
+    #[test]
+    fn invalid_input() {
+        let input = "2-4,6-8\n2-x,4-5";
+        let err = Day4::parse(input).err().unwrap().locate(input);
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), Some(3));
+        assert_eq!(err.text(), Some("x"));
+    }
+}
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use crate::error::{parse_number, split_once, Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day5 {
+    instructions: Vec<Instruction>,
+    stacks: Vec<Vec<char>>,
+}
+
+impl Day5 {
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn rearrange(&self, multiple: bool) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for inst in &self.instructions {
+            inst.apply(&mut stacks, multiple)?;
+        }
+        stacks
+            .iter()
+            .enumerate()
+            .map(|(idx, x)| {
+                x.last()
+                    .ok_or_else(|| Error::new(format!("stack {} ends up empty", idx + 1)))
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day5 {
+    fn parse(input: &str) -> Result<Self> {
+        let (instructions, stacks) = parse_input(input)?;
+        Ok(Self {
+            instructions,
+            stacks,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.rearrange(false)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.rearrange(true)?.into())
+    }
+}
+
+fn parse_input(s: &str) -> Result<(Vec<Instruction>, Vec<Vec<char>>)> {
+    let (stacks, instructions) = s
+        .split_once("\n\n")
+        .ok_or_else(|| Error::new("expected a blank line between stacks and instructions"))?;
+
+    let mut stacks_rv = stacks.lines().rev();
+    let last_line = stacks_rv
+        .next()
+        .ok_or_else(|| Error::new("missing stack numbers"))?;
+    let num_stacks: u8 = last_line
+        .split_ascii_whitespace()
+        .next_back()
+        .map(parse_number)
+        .ok_or_else(|| Error::invalid(last_line, "missing stack numbers"))??;
+
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; num_stacks as usize];
+    for l in stacks_rv {
+        let mut column = 0;
+        let mut ws_count = 0;
+        let split = l.split(' ');
+
+        for s in split {
+            if s.is_empty() {
+                ws_count += 1;
+                if ws_count > 3 {
+                    ws_count = 0;
+                    column += 1;
+                }
+                continue;
+            } else {
+                let element = match s.as_bytes() {
+                    [b'[', c, b']'] => *c as char,
+                    _ => return Err(Error::invalid(s, "invalid crate")),
+                };
+                stacks
+                    .get_mut(column)
+                    .ok_or_else(|| Error::invalid(s, "crate outside of numbered stacks"))?
+                    .push(element);
+                column += 1;
+            }
+        }
+    }
+    let instructions: Vec<Instruction> = instructions
+        .lines()
+        .map(|s| {
+            let instruction = Instruction::from_str(s)?;
+            if instruction.from.max(instruction.to) >= num_stacks {
+                return Err(Error::invalid(s, "unknown stack"));
+            }
+            Ok(instruction)
+        })
+        .collect::<Result<_>>()?;
+    Ok((instructions, stacks))
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    count: u8,
+    from: u8,
+    to: u8,
+}
+
+impl Instruction {
+    pub fn apply(&self, stacks: &mut [Vec<char>], multiple: bool) -> Result<()> {
+        let mut to_move: Vec<char> = (0..self.count)
+            .map(|_| {
+                stacks[self.from as usize].pop().ok_or_else(|| {
+                    Error::new(format!("cannot move from empty stack {}", self.from + 1))
+                })
+            })
+            .collect::<Result<_>>()?;
+        if multiple {
+            to_move.reverse();
+        }
+        stacks[self.to as usize].extend_from_slice(&to_move);
+        Ok(())
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        fn stack_number(s: &str) -> Result<u8> {
+            match parse_number::<u8>(s)? {
+                0 => Err(Error::invalid(s, "stack numbers start at 1")),
+                n => Ok(n - 1),
+            }
+        }
+
+        let (first, second) = split_once(s, " from ")?;
+        let count: u8 = parse_number(split_once(first, " ")?.1)?;
+        let (from, to) = split_once(second, " to ")?;
+        Ok(Self {
+            count,
+            from: stack_number(from)?,
+            to: stack_number(to)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod day5_tests {
+    use super::Day5;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3  
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day5::parse(INPUT).unwrap().part1(), Ok("CMZ".into()));
+    }
+    #[test]
+    fn part2() {
+        assert_eq!(Day5::parse(INPUT).unwrap().part2(), Ok("MCD".into()));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let input = INPUT.replace("move 3 from 1", "move 3 from 4");
+        let err = Day5::parse(&input).err().unwrap().locate(&input);
+        assert_eq!((err.line(), err.message()), (Some(7), "unknown stack"));
+
+        let input = INPUT.replace("move 3 from 1", "move 9 from 1");
+        assert!(Day5::parse(&input).unwrap().part1().is_err());
+    }
+}
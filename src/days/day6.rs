@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+fn find(signal: &[char], windows_len: usize) -> Result<usize> {
+    signal
+        .windows(windows_len)
+        .enumerate()
+        .find(|(_, chars)| HashSet::<&char>::from_iter(chars.iter()).len() == windows_len)
+        .map(|(idx, _)| idx + windows_len)
+        .ok_or_else(|| {
+            Error::new(format!(
+                "no marker of {} distinct characters found",
+                windows_len
+            ))
+        })
+}
+
+pub struct Day6 {
+    signal: Vec<char>,
+}
+
+impl Solution for Day6 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            signal: input.chars().collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(find(&self.signal, 4)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(find(&self.signal, 14)?.into())
+    }
+}
+
+#[cfg(test)]
+mod day6_tests {
+    use super::Day6;
+    use crate::solution::Solution;
+
+    const CASES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+    #[test]
+    fn part1() {
+        for (input, expected, _) in CASES {
+            assert_eq!(Day6::parse(input).unwrap().part1(), Ok(expected.into()))
+        }
+    }
+
+    #[test]
+    fn part2() {
+        for (input, _, expected) in CASES {
+            assert_eq!(Day6::parse(input).unwrap().part2(), Ok(expected.into()))
+        }
+    }
+}
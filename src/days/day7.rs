@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::error::{parse_number, split_once, Error, Result};
+use crate::solution::{Answer, Solution};
+
+fn parse_dirs(input: &str) -> Result<HashMap<PathBuf, usize>> {
+    let mut current_path = PathBuf::from("/");
+    let mut dirs: HashMap<PathBuf, usize> = HashMap::new();
+
+    for line in input.lines() {
+        if let Some(line) = line.strip_prefix("$ ") {
+            if let Some(target) = line.strip_prefix("cd ") {
+                if target == ".." {
+                    current_path.pop();
+                } else if target == "/" {
+                    current_path = Path::new("/").to_owned();
+                } else {
+                    current_path.push(Path::new(&target));
+                }
+            }
+        } else if !line.starts_with("dir ") {
+            let (size, _) = split_once(line, " ")?;
+            let size: usize = parse_number(size)?;
+
+            for ancestor in current_path.ancestors() {
+                *dirs.entry(ancestor.to_path_buf()).or_insert(0) += size
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+pub struct Day7 {
+    dirs: HashMap<PathBuf, usize>,
+}
+
+impl Day7 {
+    pub fn dirs(&self) -> &HashMap<PathBuf, usize> {
+        &self.dirs
+    }
+}
+
+impl Solution for Day7 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            dirs: parse_dirs(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self
+            .dirs
+            .values()
+            .cloned()
+            .filter(|size| size <= &100_000usize)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        const REQUIRED_SIZE: usize = 30_000_000usize;
+        const MAX_SIZE: usize = 70_000_000usize;
+
+        let root_size = self
+            .dirs
+            .get(Path::new("/"))
+            .ok_or_else(|| Error::new("no files found"))?;
+        let free = MAX_SIZE
+            .checked_sub(*root_size)
+            .ok_or_else(|| Error::new("files do not fit on the disk"))?;
+        let required_to_free = REQUIRED_SIZE.saturating_sub(free);
+
+        let smallest = self
+            .dirs
+            .values()
+            .filter(|&size| size >= &required_to_free)
+            .min()
+            .ok_or_else(|| Error::new("no directory is large enough"))?;
+        Ok((*smallest).into())
+    }
+}
+
+#[cfg(test)]
+mod day7_tests {
+    use super::Day7;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day7::parse(INPUT).unwrap().part1(), Ok(95437.into()))
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day7::parse(INPUT).unwrap().part2(), Ok(24933642.into()))
+    }
+}
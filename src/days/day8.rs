@@ -0,0 +1,288 @@
+use std::cmp::Reverse;
+use std::iter;
+
+use crate::error::{parse_number, Error, Result};
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::render::{self, Rgb, Style};
+use crate::solution::{Answer, Solution};
+
+pub struct Day8 {
+    trees: Grid<u8>,
+}
+
+impl Day8 {
+    pub fn trees(&self) -> &Grid<u8> {
+        &self.trees
+    }
+}
+
+impl Solution for Day8 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            trees: parse_trees(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let visible = visibility(&self.trees);
+        Ok(visible.iter().filter(|(_, &v)| v).count().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let best = scenic_scores(&self.trees)
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .ok_or_else(|| Error::new("no trees in input"))?;
+        Ok(best.into())
+    }
+}
+
+pub fn parse_trees(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |_, c| {
+        parse_number(c).map_err(|_| Error::invalid(c, "invalid tree height"))
+    })
+}
+
+/// Every line of sight through the forest, generated lazily: for each
+/// direction, one line from every tree on the edge it starts from.
+fn sweeps(trees: &Grid<u8>) -> impl Iterator<Item = impl Iterator<Item = Point> + '_> + '_ {
+    Direction::ALL.into_iter().flat_map(move |direction| {
+        trees
+            .points()
+            .filter(move |&p| trees.step(p, direction.opposite()).is_none())
+            .map(move |start| iter::once(start).chain(trees.walk(start, direction)))
+    })
+}
+
+/// Which trees are visible from outside the forest, in one pass per direction:
+/// a tree is visible along a sweep if it's taller than every tree before it.
+pub fn visibility(trees: &Grid<u8>) -> Grid<bool> {
+    let mut visible = trees.map(|_, _| false);
+    for line in sweeps(trees) {
+        let mut tallest = None;
+        for p in line {
+            if tallest.is_none_or(|tallest| trees[p] > tallest) {
+                visible[p] = true;
+                tallest = Some(trees[p]);
+            }
+        }
+    }
+    visible
+}
+
+/// The scenic score of every tree, in one pass per direction. A stack of the
+/// trees seen so far that aren't hidden behind a taller one gives, for each
+/// tree, the nearest tree at least as tall looking back along the sweep.
+pub fn scenic_scores(trees: &Grid<u8>) -> Grid<usize> {
+    let mut scores = trees.map(|_, _| 1);
+    let mut stack: Vec<(usize, u8)> = Vec::new();
+    for line in sweeps(trees) {
+        stack.clear();
+        for (i, p) in line.enumerate() {
+            let height = trees[p];
+            while stack.last().is_some_and(|&(_, h)| h < height) {
+                stack.pop();
+            }
+            let blocker = stack.last().map_or(0, |&(j, _)| j);
+            scores[p] *= i - blocker;
+            stack.push((i, height));
+        }
+    }
+    scores
+}
+
+/// The tree with the highest scenic score, the first one in reading order on ties.
+pub fn best_tree(scores: &Grid<usize>) -> Option<Point> {
+    scores
+        .iter()
+        .max_by_key(|&(p, &score)| (score, Reverse((p.y, p.x))))
+        .map(|(p, _)| p)
+}
+
+/// Draws the forest with visible trees as their height and hidden ones as `.`,
+/// or with [`Style::Ansi`] every height shown, visible trees on green.
+pub fn render_visibility(trees: &Grid<u8>, visible: &Grid<bool>, style: Style) -> String {
+    let mut out = String::new();
+    for (p, &height) in trees.iter() {
+        let digit = (b'0' + height) as char;
+        match (style, visible[p]) {
+            (Style::Plain, true) => out.push(digit),
+            (Style::Plain, false) => out.push('.'),
+            (Style::Ansi, true) => out.push_str(&render::ansi(digit, Rgb::BLACK, Rgb(90, 200, 90))),
+            (Style::Ansi, false) => {
+                out.push_str(&render::ansi(digit, Rgb(150, 150, 150), Rgb(30, 30, 30)))
+            }
+        }
+        if p.x as usize + 1 == trees.width() {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Scenic scores scaled to `0.0..=1.0` on a log scale, since a handful of
+/// trees score orders of magnitude higher than the rest.
+fn normalised(scores: &Grid<usize>) -> Grid<f64> {
+    let max = scores.iter().map(|(_, &s)| s).max().unwrap_or(0);
+    let max = (max as f64).ln_1p().max(f64::MIN_POSITIVE);
+    scores.map(|_, &score| (score as f64).ln_1p() / max)
+}
+
+/// Draws the scenic scores as a heatmap with the best tree marked `X`: plain
+/// uses a density ramp, [`Style::Ansi`] coloured cells.
+pub fn render_scores(scores: &Grid<usize>, style: Style) -> String {
+    const RAMP: &[u8] = b" .:-=+*#%@";
+    let best = best_tree(scores);
+    let levels = normalised(scores);
+    let mut out = String::new();
+    for (p, &t) in levels.iter() {
+        let is_best = Some(p) == best;
+        match style {
+            Style::Plain if is_best => out.push('X'),
+            Style::Plain => out.push(RAMP[(t * (RAMP.len() - 1) as f64).round() as usize] as char),
+            Style::Ansi if is_best => out.push_str(&render::ansi('X', Rgb::WHITE, Rgb::RED)),
+            Style::Ansi => out.push_str(&render::ansi(' ', Rgb::BLACK, render::gradient(t))),
+        }
+        if p.x as usize + 1 == scores.width() {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn score_colours(scores: &Grid<usize>) -> Grid<Rgb> {
+    let mut colours = normalised(scores).map(|_, &t| render::gradient(t));
+    if let Some(best) = best_tree(scores) {
+        colours[best] = Rgb::RED;
+    }
+    colours
+}
+
+/// The scenic-score heatmap as a PPM image, with the best tree in red.
+pub fn scores_ppm(scores: &Grid<usize>, scale: usize) -> Vec<u8> {
+    render::ppm(&score_colours(scores), scale)
+}
+
+/// The scenic-score heatmap as an SVG image, with the best tree in red.
+pub fn scores_svg(scores: &Grid<usize>, scale: usize) -> String {
+    render::svg(&score_colours(scores), scale) + "</svg>\n"
+}
+
+#[cfg(test)]
+mod day8_tests {
+    use super::{
+        best_tree, parse_trees, render_scores, render_visibility, scenic_scores, scores_ppm,
+        visibility, Day8,
+    };
+    use crate::grid::Grid;
+    use crate::point::{Direction, Point};
+    use crate::render::Style;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"30373
+25512
+65332
+33549
+35390"#;
+    const INPUT_FULL: &str = include_str!("../inputs/day8.txt");
+    #[test]
+    fn part1() {
+        assert_eq!(Day8::parse(INPUT).unwrap().part1(), Ok(21.into()));
+        assert_eq!(Day8::parse(INPUT_FULL).unwrap().part1(), Ok(1711.into()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day8::parse(INPUT).unwrap().part2(), Ok(8.into()));
+        assert_eq!(Day8::parse(INPUT_FULL).unwrap().part2(), Ok(301392.into()));
+    }
+
+    /// Checks one tree against [`visibility`]: it's visible if every tree
+    /// between it and an edge, in at least one direction, is shorter.
+    fn is_visible(trees: &Grid<u8>, tree: Point) -> bool {
+        let height = trees[tree];
+        Direction::ALL.iter().any(|&direction| {
+            trees
+                .walk(tree, direction)
+                .all(|other| trees[other] < height)
+        })
+    }
+
+    /// Checks one tree against [`scenic_scores`]: the product of how many trees
+    /// can be seen in each direction, stopping at the first one at least as tall.
+    fn scenic_score(trees: &Grid<u8>, tree: Point) -> usize {
+        let height = trees[tree];
+        Direction::ALL
+            .iter()
+            .map(|&direction| {
+                let mut count = 0;
+                for other in trees.walk(tree, direction) {
+                    count += 1;
+                    if trees[other] >= height {
+                        break;
+                    }
+                }
+                count
+            })
+            .product()
+    }
+
+    #[test]
+    fn sweeps_match_per_tree() {
+        for input in [INPUT, INPUT_FULL] {
+            let trees = parse_trees(input).unwrap();
+            let visible = visibility(&trees);
+            let scores = scenic_scores(&trees);
+            for p in trees.points() {
+                assert_eq!(visible[p], is_visible(&trees, p), "{:?}", p);
+                assert_eq!(scores[p], scenic_score(&trees, p), "{:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn non_square() {
+        let wide = "30373\n25512\n65332";
+        let tall = "303\n255\n653\n335\n353";
+        for (input, width, height, visible, best) in [
+            (wide, 5, 3, 14, 2),
+            (tall, 3, 5, 14, 2),
+            ("30373", 5, 1, 5, 0),
+            ("3\n0\n3\n7\n3", 1, 5, 5, 0),
+            ("7", 1, 1, 1, 0),
+        ] {
+            let day = Day8::parse(input).unwrap();
+            assert_eq!((day.trees().width(), day.trees().height()), (width, height));
+            assert_eq!(day.part1(), Ok(visible.into()), "{}", input);
+            assert_eq!(day.part2(), Ok(best.into()), "{}", input);
+
+            let trees = parse_trees(input).unwrap();
+            let scores = scenic_scores(&trees);
+            assert!(trees.points().all(|p| scores[p] == scenic_score(&trees, p)));
+        }
+
+        assert_eq!(Day8::parse("").unwrap().part1(), Ok(0.into()));
+        assert!(Day8::parse("").unwrap().part2().is_err());
+        assert!(Day8::parse("303\n25").is_err());
+    }
+
+    #[test]
+    fn render() {
+        let trees = parse_trees(INPUT).unwrap();
+        let visible = visibility(&trees);
+        assert_eq!(
+            render_visibility(&trees, &visible, Style::Plain),
+            "30373\n255.2\n65.32\n3.5.9\n35390\n"
+        );
+        assert!(render_visibility(&trees, &visible, Style::Ansi).contains('\x1b'));
+
+        let scores = scenic_scores(&trees);
+        assert_eq!(best_tree(&scores), Some(Point::new(2, 3)));
+        let heatmap = render_scores(&scores, Style::Plain);
+        assert_eq!(heatmap.lines().nth(3), Some(" -X* "));
+        assert_eq!(heatmap.lines().count(), 5);
+        assert!(scores_ppm(&scores, 2).starts_with(b"P6\n10 10\n255\n"));
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_number, split_once, Error, Result};
+use crate::point::{Direction, Point};
+use crate::render::{self, Rgb, Style};
+use crate::solution::{Answer, Solution};
+
+/// Where a knot at `knot` moves to stay next to `target`: if it's no longer
+/// touching, one step straight or diagonally towards it.
+pub fn follow(knot: Point, target: Point) -> Point {
+    if knot.chebyshev(target) <= 1 {
+        knot
+    } else {
+        knot + (target - knot).signum()
+    }
+}
+
+/// The unit offset the head moves by for a command: `U`, `D`, `L` and `R`
+/// from the puzzle, plus the diagonals `UL`, `UR`, `DL` and `DR`.
+fn parse_direction(dir: &str) -> Result<Point> {
+    match dir {
+        "U" => Ok(Direction::Up.offset()),
+        "R" => Ok(Direction::Right.offset()),
+        "L" => Ok(Direction::Left.offset()),
+        "D" => Ok(Direction::Down.offset()),
+        "UL" => Ok(Direction::Up.offset() + Direction::Left.offset()),
+        "UR" => Ok(Direction::Up.offset() + Direction::Right.offset()),
+        "DL" => Ok(Direction::Down.offset() + Direction::Left.offset()),
+        "DR" => Ok(Direction::Down.offset() + Direction::Right.offset()),
+        _ => Err(Error::invalid(
+            dir,
+            "unexpected direction, expected U, D, L, R, UL, UR, DL or DR",
+        )),
+    }
+}
+
+/// Each move as the head's unit offset and how many steps it takes.
+pub fn parse_input(input: &str) -> Result<Vec<(Point, i32)>> {
+    input
+        .lines()
+        .map(|l| {
+            let (dir, step) = split_once(l.trim_end_matches('\r'), " ")?;
+            let offset = parse_direction(dir)?;
+            let step = parse_number(step)?;
+            if step < 0 {
+                return Err(Error::invalid(l, "step count must not be negative"));
+            }
+            Ok((offset, step))
+        })
+        .collect::<Result<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Moves the head of `rope` by `offset` and every other knot after it.
+fn simulate_rope(offset: Point, rope: &mut [Point]) {
+    rope[0] += offset;
+    for i in 1..rope.len() {
+        let moved = follow(rope[i], rope[i - 1]);
+        if moved == rope[i] {
+            // Knots further back only move if this one did.
+            break;
+        }
+        rope[i] = moved;
+    }
+}
+
+/// A rope with a fixed number of knots, kept on the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rope<const N: usize> {
+    knots: [Point; N],
+}
+
+impl<const N: usize> Rope<N> {
+    pub fn new() -> Self {
+        Self {
+            knots: [Point::ORIGIN; N],
+        }
+    }
+
+    /// Moves the head by `offset`, a single straight or diagonal step.
+    pub fn step(&mut self, offset: Point) {
+        simulate_rope(offset, &mut self.knots);
+    }
+
+    pub fn knots(&self) -> &[Point; N] {
+        &self.knots
+    }
+
+    pub fn tail(&self) -> Point {
+        self.knots[N - 1]
+    }
+}
+
+impl<const N: usize> Default for Rope<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How many positions the tail of an `N`-knot rope visits.
+pub fn tail_positions<const N: usize>(moves: &[(Point, i32)]) -> usize {
+    let mut rope = Rope::<N>::new();
+    let mut seen = HashSet::from([rope.tail()]);
+    for &(offset, step) in moves {
+        for _ in 0..step {
+            rope.step(offset);
+            seen.insert(rope.tail());
+        }
+    }
+    seen.len()
+}
+
+/// Every state of a rope of `rope_size` knots as it follows `moves`, one
+/// state per single step of the head, starting at the origin.
+pub fn trace(moves: &[(Point, i32)], rope_size: usize) -> impl Iterator<Item = Vec<Point>> + '_ {
+    let mut rope = vec![Point::ORIGIN; rope_size];
+    moves
+        .iter()
+        .flat_map(|&(offset, step)| (0..step).map(move |_| offset))
+        .map(move |offset| {
+            simulate_rope(offset, &mut rope);
+            rope.clone()
+        })
+}
+
+/// How many positions the tail of a rope of `rope_size` knots visits, for
+/// sizes only known at run time.
+pub fn run_simulation(moves: &[(Point, i32)], rope_size: usize) -> usize {
+    let mut rope = vec![Point::ORIGIN; rope_size];
+    let mut seen = HashSet::from([Point::ORIGIN]);
+    for &(offset, step) in moves {
+        for _ in 0..step {
+            simulate_rope(offset, &mut rope);
+            seen.insert(rope[rope_size - 1]);
+        }
+    }
+    seen.len()
+}
+
+/// The positions each knot of a rope visited while following the moves,
+/// including where it started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trails {
+    knots: Vec<HashSet<Point>>,
+}
+
+impl Trails {
+    pub fn record(moves: &[(Point, i32)], rope_size: usize) -> Self {
+        let mut knots = vec![HashSet::from([Point::ORIGIN]); rope_size];
+        for rope in trace(moves, rope_size) {
+            for (trail, &knot) in knots.iter_mut().zip(&rope) {
+                trail.insert(knot);
+            }
+        }
+        Self { knots }
+    }
+
+    /// How many knots were tracked.
+    pub fn len(&self) -> usize {
+        self.knots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.knots.is_empty()
+    }
+
+    /// The positions visited by knot `knot`, 0 being the head.
+    pub fn visited(&self, knot: usize) -> &HashSet<Point> {
+        &self.knots[knot]
+    }
+
+    pub fn count(&self, knot: usize) -> usize {
+        self.knots[knot].len()
+    }
+
+    /// The top left and bottom right corners of the area knot `knot` covered.
+    pub fn bounds(&self, knot: usize) -> (Point, Point) {
+        self.knots[knot]
+            .iter()
+            .fold((Point::ORIGIN, Point::ORIGIN), |(min, max), p| {
+                (
+                    Point::new(min.x.min(p.x), min.y.min(p.y)),
+                    Point::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            })
+    }
+
+    /// Draws the trail of knot `knot` like the puzzle's tail diagrams,
+    /// cropped to the area it covered.
+    pub fn render(&self, knot: usize, style: Style) -> String {
+        let (min, max) = self.bounds(knot);
+        let viewport = Viewport {
+            origin: min,
+            width: max.x - min.x + 1,
+            height: max.y - min.y + 1,
+        };
+        render_frame(&[], self.visited(knot), &viewport, style)
+    }
+}
+
+/// The window of the plane a frame shows; `origin` is its top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub origin: Point,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Viewport {
+    /// A viewport of the given size centred on the starting point.
+    pub fn centred(width: i32, height: i32) -> Self {
+        Self {
+            origin: Point::new(-width / 2, -height / 2),
+            width,
+            height,
+        }
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        let d = p - self.origin;
+        (0..self.width).contains(&d.x) && (0..self.height).contains(&d.y)
+    }
+
+    /// Scrolls just enough to keep `p` at least `margin` cells from the edges.
+    pub fn follow(&mut self, p: Point, margin: i32) {
+        let margin_x = margin.min((self.width - 1) / 2);
+        let margin_y = margin.min((self.height - 1) / 2);
+        let d = p - self.origin;
+        self.origin.x += (d.x - margin_x).min(0) + (d.x - (self.width - 1 - margin_x)).max(0);
+        self.origin.y += (d.y - margin_y).min(0) + (d.y - (self.height - 1 - margin_y)).max(0);
+    }
+}
+
+/// The label of knot `i` in a rope of `len` knots, as the puzzle draws them.
+fn knot_label(i: usize, len: usize) -> char {
+    match i {
+        0 => 'H',
+        _ if len == 2 => 'T',
+        _ => char::from_digit(i as u32 % 10, 10).unwrap(),
+    }
+}
+
+/// Draws one frame like the puzzle examples: knots as `H`, `1`..`9` (or `T`
+/// for a two-knot rope), the start as `s`, cells the tail visited as `#`.
+/// Knots nearer the head are drawn on top.
+pub fn render_frame(
+    rope: &[Point],
+    visited: &HashSet<Point>,
+    viewport: &Viewport,
+    style: Style,
+) -> String {
+    let mut out = String::new();
+    for y in 0..viewport.height {
+        for x in 0..viewport.width {
+            let p = viewport.origin + Point::new(x, y);
+            let (c, colour) = match rope.iter().position(|&knot| knot == p) {
+                Some(0) => ('H', Some(Rgb::RED)),
+                Some(i) => (knot_label(i, rope.len()), Some(Rgb(230, 200, 60))),
+                None if p == Point::ORIGIN => ('s', None),
+                None if visited.contains(&p) => ('#', Some(Rgb(90, 140, 230))),
+                None => ('.', None),
+            };
+            match (style, colour) {
+                (Style::Ansi, Some(fg)) => out.push_str(&render::ansi(c, fg, Rgb::BLACK)),
+                _ => out.push(c),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Day9 {
+    moves: Vec<(Point, i32)>,
+}
+
+impl Day9 {
+    pub fn moves(&self) -> &[(Point, i32)] {
+        &self.moves
+    }
+}
+
+impl Solution for Day9 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            moves: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(tail_positions::<2>(&self.moves).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(tail_positions::<10>(&self.moves).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        follow, parse_input, render_frame, run_simulation, tail_positions, trace, Day9, Trails,
+        Viewport,
+    };
+    use crate::point::Point;
+    use crate::render::Style;
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"#;
+    const INPUT_LARGER: &str = r#"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20"#;
+    #[test]
+    fn part1() {
+        assert_eq!(Day9::parse(INPUT).unwrap().part1(), Ok(13.into()));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day9::parse(INPUT).unwrap().part2(), Ok(1.into()));
+        assert_eq!(Day9::parse(INPUT_LARGER).unwrap().part2(), Ok(36.into()));
+    }
+
+    #[test]
+    fn trace_and_render() {
+        let moves = parse_input(INPUT).unwrap();
+        let states: Vec<Vec<Point>> = trace(&moves, 2).collect();
+        assert_eq!(states.len(), 24);
+        assert_eq!(states[0], [Point::new(1, 0), Point::ORIGIN]);
+
+        let visited: HashSet<Point> = states.iter().map(|rope| rope[1]).collect();
+        let viewport = Viewport {
+            origin: Point::new(0, -4),
+            width: 6,
+            height: 5,
+        };
+        assert_eq!(
+            render_frame(&[], &visited, &viewport, Style::Plain),
+            "..##..\n...##.\n.####.\n....#.\ns###..\n"
+        );
+        assert_eq!(
+            render_frame(
+                states.last().unwrap(),
+                &HashSet::new(),
+                &viewport,
+                Style::Plain
+            ),
+            "......\n......\n.TH...\n......\ns.....\n"
+        );
+
+        let last = trace(&moves, 10).last().unwrap();
+        let frame = render_frame(&last, &HashSet::new(), &viewport, Style::Plain);
+        assert_eq!(frame.lines().nth(2), Some(".1H3.."));
+    }
+
+    #[test]
+    fn viewport_follows() {
+        let mut viewport = Viewport::centred(10, 6);
+        assert_eq!(viewport.origin, Point::new(-5, -3));
+        viewport.follow(Point::new(3, 0), 2);
+        assert_eq!(viewport.origin, Point::new(-4, -3));
+        viewport.follow(Point::new(-10, -10), 2);
+        assert_eq!(viewport.origin, Point::new(-12, -12));
+        assert!(viewport.contains(Point::new(-10, -10)));
+    }
+
+    #[test]
+    fn follow_rule() {
+        let target = Point::ORIGIN;
+        for x in -2..=2 {
+            for y in -2..=2 {
+                let knot = Point::new(x, y);
+                let moved = follow(knot, target);
+                assert!(moved.chebyshev(target) <= 1, "{:?}", knot);
+                assert!(moved.chebyshev(knot) <= 1, "{:?}", knot);
+                if knot.chebyshev(target) <= 1 {
+                    assert_eq!(moved, knot);
+                }
+            }
+        }
+        assert_eq!(follow(Point::new(2, 0), target), Point::new(1, 0));
+        assert_eq!(follow(Point::new(2, -1), target), Point::new(1, 0));
+        assert_eq!(follow(Point::new(-2, 2), target), Point::new(-1, 1));
+
+        let moves = parse_input(INPUT_LARGER).unwrap();
+        for size in [1, 2, 10, 30] {
+            let trails = Trails::record(&moves, size);
+            assert_eq!(trails.count(size - 1), run_simulation(&moves, size));
+        }
+        assert_eq!(tail_positions::<10>(&moves), 36);
+        assert_eq!(tail_positions::<1>(&moves), run_simulation(&moves, 1));
+    }
+
+    #[test]
+    fn trails() {
+        let moves = parse_input(INPUT_LARGER).unwrap();
+        let trails = Trails::record(&moves, 10);
+        assert_eq!(trails.len(), 10);
+        assert_eq!(trails.count(9), 36);
+        assert_eq!(trails.count(1), run_simulation(&moves, 2));
+        assert_eq!(trails.bounds(9), (Point::new(-11, -6), Point::new(10, 5)));
+        assert!(trails.visited(0).contains(&Point::new(-11, -15)));
+
+        let tail = trails.render(9, Style::Plain);
+        assert_eq!(tail.lines().count(), 12);
+        assert_eq!(tail.lines().nth(6), Some("....#......s.........#"));
+        assert_eq!(tail.lines().last(), Some(".........########....."));
+        assert_eq!(tail.matches(['#', 's']).count(), 36);
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        let moves = parse_input("UR 3\nDL 1\nDR 2\nUL 4").unwrap();
+        assert_eq!(moves[0], (Point::new(1, -1), 3));
+        assert_eq!(moves[3], (Point::new(-1, -1), 4));
+        assert_eq!(run_simulation(&moves[..1], 2), 3);
+        assert_eq!(tail_positions::<2>(&moves[..1]), 3);
+
+        let moves = parse_input(&INPUT_LARGER.replace('R', "UR").replace('D', "DL")).unwrap();
+        assert_eq!(tail_positions::<2>(&moves), run_simulation(&moves, 2));
+        assert_eq!(tail_positions::<10>(&moves), run_simulation(&moves, 10));
+        assert_eq!(
+            Trails::record(&moves, 10).count(9),
+            run_simulation(&moves, 10)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| parse_input(input).unwrap_err();
+        let e = error("R 4\nU x\nL 2");
+        assert_eq!((e.line(), e.column()), (Some(2), Some(3)));
+        assert_eq!(e.message(), "invalid number");
+
+        let e = error("R 4\nU 1\nX 2");
+        assert_eq!(
+            (e.line(), e.column(), e.text()),
+            (Some(3), Some(1), Some("X"))
+        );
+
+        assert_eq!(error("R 4\nL4").line(), Some(2));
+        assert_eq!(error("R -2").message(), "step count must not be negative");
+        assert!(Day9::parse("R 4\r\nU 2\r\n").is_ok());
+    }
+}
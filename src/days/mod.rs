@@ -0,0 +1,82 @@
+use crate::error::Result;
+use crate::solution::{self, Answer, Part, Solution};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub embedded: Option<&'static str>,
+    parse: fn(&str) -> Result<Box<dyn Solution>>,
+}
+
+impl Day {
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solution>> {
+        (self.parse)(input).map_err(|e| e.for_day(self.number))
+    }
+
+    pub fn solve(&self, solution: &dyn Solution, part: Part) -> Result<Answer> {
+        solution.solve(part).map_err(|e| e.for_day(self.number))
+    }
+}
+
+#[cfg(feature = "embedded-inputs")]
+macro_rules! embedded {
+    ($module:ident) => {
+        Some(include_str!(concat!(
+            "../inputs/",
+            stringify!($module),
+            ".txt"
+        )))
+    };
+}
+
+#[cfg(not(feature = "embedded-inputs"))]
+macro_rules! embedded {
+    ($module:ident) => {
+        None
+    };
+}
+
+macro_rules! day {
+    ($number:literal, $module:ident::$solution:ident, $title:literal) => {
+        Day {
+            number: $number,
+            title: $title,
+            embedded: embedded!($module),
+            parse: solution::parse_boxed::<$module::$solution>,
+        }
+    };
+}
+
+pub static DAYS: [Day; 13] = [
+    day!(1, day1::Day1, "Calorie Counting"),
+    day!(2, day2::Day2, "Rock Paper Scissors"),
+    day!(3, day3::Day3, "Rucksack Reorganization"),
+    day!(4, day4::Day4, "Camp Cleanup"),
+    day!(5, day5::Day5, "Supply Stacks"),
+    day!(6, day6::Day6, "Tuning Trouble"),
+    day!(7, day7::Day7, "No Space Left On Device"),
+    day!(8, day8::Day8, "Treetop Tree House"),
+    day!(9, day9::Day9, "Rope Bridge"),
+    day!(10, day10::Day10, "Cathode-Ray Tube"),
+    day!(11, day11::Day11, "Monkey in the Middle"),
+    day!(12, day12::Day12, "Hill Climbing Algorithm"),
+    day!(13, day13::Day13, "Distress Signal"),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
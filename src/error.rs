@@ -0,0 +1,166 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(Box<Details>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Details {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+    message: String,
+    context: Option<String>,
+    // Address range of the offending text. Parsers hand out slices of the
+    // original input, so this is enough to find the line and column later.
+    span: Option<Range<usize>>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(Box::new(Details {
+            day: None,
+            line: None,
+            column: None,
+            text: None,
+            message: message.into(),
+            context: None,
+            span: None,
+        }))
+    }
+
+    /// An error caused by `text`, which should be a slice of the input.
+    pub fn invalid(text: &str, message: impl Into<String>) -> Self {
+        let start = text.as_ptr() as usize;
+        let mut error = Self::new(message);
+        error.0.text = Some(text.to_owned());
+        error.0.span = Some(start..start + text.len());
+        error
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.0.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.0.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.0.column
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.0.text.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.0.day.get_or_insert(day);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.0.line.get_or_insert(line);
+        self
+    }
+
+    /// Fills in line, column and context if the offending text is part of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let base = input.as_ptr() as usize;
+        let details = &mut self.0;
+        let offset = match &details.span {
+            Some(span) if span.start >= base && span.end <= base + input.len() => span.start - base,
+            _ => return self,
+        };
+        if details.line.is_some() || !input.is_char_boundary(offset) {
+            return self;
+        }
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        details.line = Some(input[..offset].matches('\n').count() + 1);
+        details.column = Some(input[line_start..offset].chars().count() + 1);
+        details.context = Some(
+            input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        );
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let details = &self.0;
+        let location: Vec<String> = [
+            details.day.map(|day| format!("day {}", day)),
+            details.line.map(|line| format!("line {}", line)),
+            details.column.map(|column| format!("column {}", column)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", details.message)?;
+        if let Some(text) = &details.text {
+            write!(f, ": {:?}", text)?;
+        }
+        if let (Some(context), Some(column)) = (&details.context, details.column) {
+            write!(f, "\n    {}\n    {:>width$}", context, "^", width = column)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub fn parse_number<T: FromStr>(s: &str) -> Result<T> {
+    s.parse().map_err(|_| Error::invalid(s, "invalid number"))
+}
+
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| Error::invalid(s, format!("expected {:?}", delimiter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_number, Error};
+
+    #[test]
+    fn locate() {
+        let input = "1 2\n3 x 5\n";
+        let token = &input[6..7];
+        let err = parse_number::<u32>(token)
+            .unwrap_err()
+            .locate(input)
+            .for_day(4);
+
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+        assert_eq!(
+            err.to_string(),
+            "day 4, line 2, column 3: invalid number: \"x\"\n    3 x 5\n      ^"
+        );
+    }
+
+    #[test]
+    fn locate_outside_input() {
+        let input = String::from("1 2 3");
+        let err = Error::invalid("x", "invalid").locate(&input);
+        assert_eq!(err.line(), None);
+        assert_eq!(err.to_string(), "invalid: \"x\"");
+    }
+}
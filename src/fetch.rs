@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::input::Inputs;
+
+pub const YEAR: u16 = 2022;
+/// Minimum time between two requests made by the same fetcher.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+const USER_AGENT: &str = "github.com/tiberiuv/aoc_2022";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads puzzle inputs with the user's session cookie.
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            interval: DEFAULT_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let session = config.session.as_deref().ok_or_else(|| {
+            Error::new("no session token, set AOC_SESSION or `session` in aoc.json")
+        })?;
+        Ok(Self::new(config.base_url(), session))
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    /// Downloads the input for `day`, waiting first if the previous request
+    /// was less than the configured interval ago.
+    pub fn fetch(&mut self, day: u8) -> Result<String> {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+
+        let url = self.url(day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(404, _) => Error::new("input not available yet"),
+                ureq::Error::Status(400 | 500, _) => {
+                    Error::new("request rejected, the session token may be invalid or expired")
+                }
+                ureq::Error::Status(429, _) => Error::new("rate limited by the server"),
+                e => Error::new(format!("cannot fetch input: {}", e)),
+            })
+            .map_err(|e| e.for_day(day))?;
+
+        response.into_string().map_err(|e| {
+            Error::new(format!("cannot read response from {}: {}", url, e)).for_day(day)
+        })
+    }
+
+    /// Saves the input for `day` into the inputs directory unless it's already there.
+    pub fn fetch_into(&mut self, day: u8, inputs: &Inputs) -> Result<Fetched> {
+        let path = inputs.path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.fetch(day)?;
+        fs::create_dir_all(inputs.dir())
+            .map_err(|e| Error::new(format!("cannot create {}: {}", inputs.dir().display(), e)))?;
+        // Write through a temporary file so an interrupted download never
+        // leaves a partial input behind that would be treated as cached.
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input)
+            .and_then(|()| fs::rename(&partial, &path))
+            .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{Fetched, Fetcher};
+    use crate::input::Inputs;
+    use crate::testing::TempDir;
+
+    /// Serves `responses` in order, one per connection, returning the request lines and cookies seen.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut request = line.trim_end().to_owned();
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some(cookie) = line.strip_prefix("Cookie: ") {
+                        request = format!("{} {}", request, cookie.trim_end());
+                    }
+                }
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let dir = TempDir::new("fetch");
+        let inputs = Inputs::new(&*dir);
+        let (url, server) = serve(vec![(200, "1000\n2000\n"), (404, "not yet")]);
+        let mut fetcher = Fetcher::new(&url, "abc\n").with_interval(Duration::ZERO);
+
+        assert_eq!(
+            fetcher.fetch_into(1, &inputs),
+            Ok(Fetched::Downloaded(dir.join("day1.txt")))
+        );
+        assert_eq!(
+            fetcher.fetch_into(1, &inputs),
+            Ok(Fetched::Cached(dir.join("day1.txt")))
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("day1.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+
+        let err = fetcher.fetch_into(2, &inputs).unwrap_err();
+        assert_eq!(err.message(), "input not available yet");
+        assert!(!dir.join("day2.txt").exists());
+
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /2022/day/1/input HTTP/1.1 session=abc",
+                "GET /2022/day/2/input HTTP/1.1 session=abc"
+            ]
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::point::{Direction, Point, DIAGONALS};
+
+/// A rectangular grid stored row by row in a flat vector, indexed by
+/// [`Point`]s with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self> {
+        if !cells.len().is_multiple_of(width) {
+            return Err(Error::new(format!(
+                "{} cells don't fill rows of {}",
+                cells.len(),
+                width
+            )));
+        }
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses a character map, one row per line, calling `cell` with each
+    /// character as a slice of `s` so it can report errors at that position.
+    /// All rows must have the same length.
+    pub fn parse(s: &str, mut cell: impl FnMut(Point, &str) -> Result<T>) -> Result<Self> {
+        let mut cells = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in s.lines().enumerate() {
+            // `lines` leaves the `\r` of a final CRLF-less line in place.
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let mut x = 0;
+            for (i, c) in line.char_indices() {
+                cells.push(cell(Point::new(x, y as i32), &line[i..i + c.len_utf8()])?);
+                x += 1;
+            }
+            let x = x as usize;
+            match width {
+                None => width = Some(x),
+                Some(width) if width != x => {
+                    return Err(Error::invalid(
+                        line,
+                        format!("expected {} columns, found {}", width, x),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as i32).contains(&p.x) && (0..self.height as i32).contains(&p.y)
+    }
+
+    pub fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// The point one step from `p` in `direction`, if it's inside the grid.
+    pub fn step(&self, p: Point, direction: Direction) -> Option<Point> {
+        Some(p + direction.offset()).filter(|&next| self.contains(next))
+    }
+
+    /// Every point from `p` (exclusive) to the edge in `direction`.
+    pub fn walk(&self, p: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        let mut current = Some(p).filter(|&c| self.contains(c));
+        std::iter::from_fn(move || {
+            current = self.step(current?, direction);
+            current
+        })
+    }
+
+    /// The up to 4 orthogonally adjacent points inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(p, direction))
+    }
+
+    /// The up to 8 adjacent points inside the grid, including diagonals.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let diagonals = DIAGONALS
+            .into_iter()
+            .map(move |delta| p + delta)
+            .filter(|&next| self.contains(next));
+        self.neighbours4(p).chain(diagonals)
+    }
+
+    /// Every point in the grid in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(p, cell)| f(p, cell)).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Draws the grid one character per cell, one line per row.
+    pub fn render(&self, mut f: impl FnMut(Point, &T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for (p, cell) in self.iter() {
+            out.push(f(p, cell));
+            if p.x as usize + 1 == self.width {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", p, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::error::parse_number;
+    use crate::point::{Direction, Point};
+
+    fn digits(s: &str) -> Grid<u8> {
+        Grid::parse(s, |_, c| parse_number(c)).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456\r\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let input = "123\n45\n";
+        let err = Grid::parse(input, |_, c| parse_number::<u8>(c))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            (err.line(), err.message()),
+            (Some(2), "expected 3 columns, found 2")
+        );
+
+        let input = "12\n4x\n";
+        let err = Grid::parse(input, |_, c| parse_number::<u8>(c))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((err.line(), err.column()), (Some(2), Some(2)));
+
+        assert!(Grid::parse("", |_, c| parse_number::<u8>(c))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789");
+        let values = |points: Vec<Point>| points.into_iter().map(|c| grid[c]).collect::<Vec<_>>();
+
+        assert_eq!(values(grid.neighbours4(Point::new(0, 0)).collect()), [4, 2]);
+        assert_eq!(
+            values(grid.neighbours4(Point::new(1, 1)).collect()),
+            [2, 8, 4, 6]
+        );
+        assert_eq!(
+            values(grid.neighbours8(Point::new(2, 2)).collect()),
+            [6, 8, 5]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            values(grid.walk(Point::new(1, 2), Direction::Up).collect()),
+            [5, 2]
+        );
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.neighbours4(Point::new(-1, 0)).count(), 1);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(
+            grid.render(|_, &d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#\n"
+        );
+        assert_eq!(Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]), Ok(grid));
+        assert!(Grid::from_vec(4, vec![1, 2, 3]).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::days::Day;
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(s: &str) -> Self {
+        match s {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl Source {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::File(path) => read_file(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::new(format!("cannot read stdin: {}", e)))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Resolves puzzle inputs: an explicit source if one was given, otherwise
+/// `dayN.txt` in the inputs directory, falling back to the embedded input.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+    source: Option<Source>,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            source: None,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.inputs_dir())
+    }
+
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    pub fn load(&self, day: &Day) -> Result<String> {
+        if let Some(source) = &self.source {
+            return source.read();
+        }
+
+        let path = self.path(day.number);
+        if path.exists() {
+            return read_file(&path);
+        }
+        day.embedded.map(str::to_owned).ok_or_else(|| {
+            Error::new(format!("no input found at {}", path.display())).for_day(day.number)
+        })
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("cannot read {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{Inputs, Source};
+    use crate::days;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn load_from_dir() {
+        let dir = TempDir::new("inputs");
+        fs::write(dir.join("day6.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+
+        let inputs = Inputs::new(&*dir);
+        let day = days::get(6).unwrap();
+        assert_eq!(inputs.load(day).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        let missing = Inputs::new(dir.join("missing"));
+        assert_eq!(missing.load(day).ok().as_deref(), day.embedded);
+
+        let file =
+            Inputs::new("unused").with_source(Source::from(dir.join("day6.txt").to_str().unwrap()));
+        assert_eq!(file.load(day).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    }
+}
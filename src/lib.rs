@@ -0,0 +1,30 @@
+//! Advent of Code 2022 solutions.
+//!
+//! Every puzzle lives in [`days`] as a `dayN` module exposing a `DayN` type
+//! implementing [`Solution`], along with the types used to model it. The
+//! [`DAYS`] registry lists them all for tooling that iterates over days.
+
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod point;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+#[cfg(test)]
+mod testing;
+
+pub use days::{Day, DAYS};
+pub use error::{Error, Result};
+pub use solution::{Answer, Part, Solution};
+
+#[doc(hidden)]
+pub use serde_json;
@@ -1,3 +1,616 @@
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use aoc_2022::answers::{self, Answers, Status};
+use aoc_2022::bench;
+use aoc_2022::config::Config;
+use aoc_2022::days::day8::{self, Day8};
+use aoc_2022::days::day9::{self, Day9, Trails, Viewport};
+use aoc_2022::days::{self, day12::Day12, Day};
+use aoc_2022::error::Error;
+use aoc_2022::input::{Inputs, Source};
+use aoc_2022::point::Point;
+use aoc_2022::render::Style;
+use aoc_2022::runner::{self, Format};
+use aoc_2022::scaffold;
+use aoc_2022::solution::{Answer, Part, Solution};
+
+const USAGE: &str = "Usage: aoc run [DAYS] [--part <1|2>] [--input <PATH|->] [--inputs-dir <DIR>] [--format <FORMAT>]
+       aoc bench [DAYS] [--iterations <N>] [--inputs-dir <DIR>] [--format <FORMAT>]
+       aoc fetch [DAYS] [--inputs-dir <DIR>]
+       aoc draw <DAY> [--ansi] [--svg <PATH>] [--ppm <PATH>] [--scale <N>] [--input <PATH|->]
+                      [--knots <N>] [--delay <MS>] [--viewport <W>x<H>] [--dump] [--trail <K>]
+       aoc new <DAY> [--title <TITLE>]
+       aoc verify [DAYS] [--inputs-dir <DIR>] [--answers <PATH>]
+       aoc record <DAY> [--part <1|2>] [--answer <VALUE>] [--inputs-dir <DIR>] [--answers <PATH>]
+
+DAYS is a single day (7), an inclusive range (3..=7 or 3-7), a list (1,4,9) or
+`all` (default). Each selected day runs once, in order.
+
+FORMAT is `plain` (default), `table`, or `json` for one record per day and part
+with its answer, answer type and parse/solve times in nanoseconds.
+
+Inputs are read from --input (`-` for stdin) when running a single day, otherwise
+from dayN.txt in --inputs-dir, $AOC_INPUTS_DIR, `inputs_dir` in aoc.json or
+src/inputs, in that order, falling back to the input embedded at build time.
+
+Confirmed answers are kept in --answers, $AOC_ANSWERS, `answers` in aoc.json or
+answers.json. `verify` compares every part against them and `record` stores the
+current answer (or --answer) for a day.
+
+`bench` times parsing and each part separately over --iterations runs (default 10)
+and reports min/median/mean as a table, or in nanoseconds with --format json.
+
+`fetch` downloads missing inputs into the inputs directory using the session
+cookie from $AOC_SESSION or `session` in aoc.json. Existing files are never
+downloaded again. $AOC_BASE_URL or `base_url` overrides the server. It needs the
+`fetch` feature, which is on by default.
+
+`draw` prints a picture of a day's solution, with colours when --ansi is given, and
+can also export it as an SVG or PPM image with each cell --scale pixels wide
+(default 8). Day 8 shows the visible trees and a scenic-score heatmap with the best
+tree marked X; day 12 shows the shortest route over the height map.
+
+Day 9 animates a rope of --knots knots (default 10) with --delay milliseconds
+between steps (default 40) in a --viewport (default 60x24) that follows the head.
+--dump prints every frame one after another instead of animating. --trail draws
+the cells knot K (0 is the head) visited instead, after a count for every knot.
+
+`new` must be run from the crate root. It creates src/days/dayN.rs with an example
+test module, src/bin/dayN.rs and an empty src/inputs/dayN.txt, and registers the
+day in src/days/mod.rs.";
+
+enum CliError {
+    Usage(String),
+    Failed(Error),
+}
+
+impl From<Error> for CliError {
+    fn from(e: Error) -> Self {
+        Self::Failed(e)
+    }
+}
+
+fn usage(message: impl Into<String>) -> CliError {
+    CliError::Usage(message.into())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => {}
+        Err(CliError::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(CliError::Failed(e)) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => ("help", args),
+    };
+
+    match command {
+        "run" => run_days(args),
+        "bench" => run_bench(args),
+        #[cfg(feature = "fetch")]
+        "fetch" => fetch(args),
+        #[cfg(not(feature = "fetch"))]
+        "fetch" => Err(Error::new("aoc was built without the `fetch` feature").into()),
+        "draw" => draw(args),
+        "new" => new_day(args),
+        "verify" => verify(args),
+        "record" => record(args),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(usage(format!("unknown command {}", command))),
+    }
+}
+
+/// Flags shared by several commands; each command lists the ones it takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flag {
+    Part,
+    Input,
+    InputsDir,
+    Format,
+    Answers,
+}
+
+/// The arguments left to read, so command-specific flags can take their values.
+struct Args<'a>(std::slice::Iter<'a, String>);
+
+impl<'a> Args<'a> {
+    fn value(&mut self, flag: &str) -> Result<&'a str, CliError> {
+        self.0
+            .next()
+            .map(String::as_str)
+            .ok_or_else(|| usage(format!("missing value for {}", flag)))
+    }
+
+    fn positive_number(&mut self, flag: &str) -> Result<usize, CliError> {
+        self.value(flag)?
+            .parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| usage(format!("{} must be a positive number", flag)))
+    }
+}
+
+/// The shared flags a command was given, and its DAYS (or DAY) argument.
+struct Options<'a> {
+    days: Option<&'a str>,
+    parts: Vec<Part>,
+    input: Option<&'a str>,
+    inputs_dir: Option<&'a str>,
+    format: Option<Format>,
+    answers: Option<&'a str>,
+}
+
+impl<'a> Options<'a> {
+    /// Reads the shared flags in `accepted`, handing every other argument to
+    /// `other` first, which returns whether it took it.
+    fn parse(
+        args: &'a [String],
+        accepted: &[Flag],
+        mut other: impl FnMut(&str, &mut Args<'a>) -> Result<bool, CliError>,
+    ) -> Result<Self, CliError> {
+        let mut options = Options {
+            days: None,
+            parts: Part::ALL.to_vec(),
+            input: None,
+            inputs_dir: None,
+            format: None,
+            answers: None,
+        };
+
+        let mut args = Args(args.iter());
+        while let Some(arg) = args.0.next() {
+            let flag = match arg.as_str() {
+                "-p" | "--part" => Some(Flag::Part),
+                "-i" | "--input" => Some(Flag::Input),
+                "--inputs-dir" => Some(Flag::InputsDir),
+                "-f" | "--format" => Some(Flag::Format),
+                "--answers" => Some(Flag::Answers),
+                _ => None,
+            };
+            match flag {
+                Some(flag) if accepted.contains(&flag) => {
+                    let value = args.value(arg)?;
+                    match flag {
+                        Flag::Part => {
+                            options.parts =
+                                vec![value.parse().map_err(|e: Error| usage(e.to_string()))?]
+                        }
+                        Flag::Input => options.input = Some(value),
+                        Flag::InputsDir => options.inputs_dir = Some(value),
+                        Flag::Format => {
+                            options.format =
+                                Some(value.parse().map_err(|e: Error| usage(e.to_string()))?)
+                        }
+                        Flag::Answers => options.answers = Some(value),
+                    }
+                }
+                None if other(arg, &mut args)? => {}
+                None if options.days.is_none() => options.days = Some(arg),
+                _ => return Err(usage(format!("unexpected argument {}", arg))),
+            }
+        }
+        Ok(options)
+    }
+
+    /// The selected days, all of them if none were given.
+    fn days(&self) -> Result<Vec<u8>, CliError> {
+        parse_days(self.days.unwrap_or("all"))
+    }
+
+    /// The one day a command like `draw` works on.
+    fn day(&self, command: &str) -> Result<&'static Day, CliError> {
+        match parse_days(self.days.ok_or_else(|| usage("missing day"))?)?[..] {
+            [day] => Ok(days::get(day).expect("parse_days only returns registered days")),
+            _ => Err(usage(format!("{} takes a single day", command))),
+        }
+    }
+
+    /// Where to read the inputs of `days` from.
+    fn inputs(&self, config: &Config, days: &[u8]) -> Result<Inputs, CliError> {
+        let inputs = match self.inputs_dir {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::from_config(config),
+        };
+        match self.input {
+            Some(_) if days.len() != 1 => Err(usage("--input can only be used with a single day")),
+            Some(path) => Ok(inputs.with_source(Source::from(path))),
+            None => Ok(inputs),
+        }
+    }
+
+    fn answers(&self, config: &Config) -> PathBuf {
+        self.answers.map_or_else(|| config.answers(), PathBuf::from)
+    }
+}
+
+fn run_days(args: &[String]) -> Result<(), CliError> {
+    let accepted = [Flag::Part, Flag::Input, Flag::InputsDir, Flag::Format];
+    let options = Options::parse(args, &accepted, |_, _| Ok(false))?;
+    let days = options.days()?;
+    let inputs = options.inputs(&Config::load()?, &days)?;
+    let format = options.format.unwrap_or(Format::Plain);
+    Ok(runner::run(&days, &options.parts, &inputs, format)?)
+}
+
+fn run_bench(args: &[String]) -> Result<(), CliError> {
+    let mut iterations = bench::DEFAULT_ITERATIONS;
+    let options = Options::parse(args, &[Flag::InputsDir, Flag::Format], |arg, args| {
+        match arg {
+            "-n" | "--iterations" => iterations = args.positive_number(arg)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    let days = options.days()?;
+    let inputs = options.inputs(&Config::load()?, &days)?;
+    let timings = bench::bench(&days, &inputs, iterations)?;
+
+    if options.format == Some(Format::Json) {
+        let report = serde_json::to_string_pretty(&timings)
+            .map_err(|e| Error::new(format!("cannot serialize report: {}", e)))?;
+        println!("{}", report);
+    } else {
+        println!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+            "day", "phase", "min", "median", "mean"
+        );
+        for timing in &timings {
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                timing.day,
+                timing.phase.to_string(),
+                format!("{:.2?}", timing.stats.min),
+                format!("{:.2?}", timing.stats.median),
+                format!("{:.2?}", timing.stats.mean),
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(args: &[String]) -> Result<(), CliError> {
+    use aoc_2022::fetch::{Fetched, Fetcher};
+
+    let options = Options::parse(args, &[Flag::InputsDir], |_, _| Ok(false))?;
+    let config = Config::load()?;
+    let days = options.days()?;
+    let inputs = options.inputs(&config, &days)?;
+    let mut fetcher = Fetcher::from_config(&config)?;
+    for day in days {
+        match fetcher.fetch_into(day, &inputs)? {
+            Fetched::Cached(path) => println!("Day {}: already at {}", day, path.display()),
+            Fetched::Downloaded(path) => println!("Day {}: saved to {}", day, path.display()),
+        }
+    }
+    Ok(())
+}
+
+struct DrawOptions {
+    style: Style,
+    svg: Option<PathBuf>,
+    ppm: Option<PathBuf>,
+    scale: usize,
+    knots: usize,
+    delay: Duration,
+    viewport: (i32, i32),
+    dump: bool,
+    trail: Option<usize>,
+}
+
+fn draw(args: &[String]) -> Result<(), CliError> {
+    let mut draw = DrawOptions {
+        style: Style::Plain,
+        svg: None,
+        ppm: None,
+        scale: 8,
+        knots: 10,
+        delay: Duration::from_millis(40),
+        viewport: (60, 24),
+        dump: false,
+        trail: None,
+    };
+
+    let options = Options::parse(args, &[Flag::Input, Flag::InputsDir], |arg, args| {
+        match arg {
+            "--ansi" => draw.style = Style::Ansi,
+            "--svg" => draw.svg = Some(args.value(arg)?.into()),
+            "--ppm" => draw.ppm = Some(args.value(arg)?.into()),
+            "--scale" => draw.scale = args.positive_number(arg)?,
+            "--knots" => draw.knots = args.positive_number(arg)?,
+            "--delay" => {
+                let millis = args
+                    .value(arg)?
+                    .parse()
+                    .map_err(|_| usage("--delay must be a number of milliseconds"))?;
+                draw.delay = Duration::from_millis(millis);
+            }
+            "--viewport" => {
+                draw.viewport = args
+                    .value(arg)?
+                    .split_once('x')
+                    .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                    .filter(|&(w, h)| w > 0 && h > 0)
+                    .ok_or_else(|| usage("--viewport must look like 60x24"))?;
+            }
+            "--dump" => draw.dump = true,
+            "--trail" => {
+                let knot = args
+                    .value(arg)?
+                    .parse()
+                    .map_err(|_| usage("--trail must be a knot number"))?;
+                draw.trail = Some(knot);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    let day = options.day("draw")?;
+    let input = options.inputs(&Config::load()?, &[day.number])?.load(day)?;
+    match day.number {
+        8 => draw_day8(&input, &draw),
+        9 => draw_day9(&input, &draw),
+        12 => draw_day12(&input, &draw),
+        n => Err(usage(format!("there is no drawing for day {}", n))),
+    }
+}
+
+fn parse_day<S: Solution>(number: u8, input: &str) -> Result<S, Error> {
+    S::parse(input).map_err(|e| e.locate(input).for_day(number))
+}
+
+fn write_file(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), Error> {
+    fs::write(path, contents)
+        .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+}
+
+fn draw_day8(input: &str, options: &DrawOptions) -> Result<(), CliError> {
+    let day: Day8 = parse_day(8, input)?;
+    let trees = day.trees();
+    let visible = day8::visibility(trees);
+    let scores = day8::scenic_scores(trees);
+
+    println!(
+        "{}",
+        day8::render_visibility(trees, &visible, options.style)
+    );
+    print!("{}", day8::render_scores(&scores, options.style));
+    if let Some(best) = day8::best_tree(&scores) {
+        println!(
+            "Best tree: x {}, y {}, scenic score {}",
+            best.x, best.y, scores[best]
+        );
+    }
+    if let Some(path) = &options.svg {
+        write_file(path, day8::scores_svg(&scores, options.scale))?;
+    }
+    if let Some(path) = &options.ppm {
+        write_file(path, day8::scores_ppm(&scores, options.scale))?;
+    }
+    Ok(())
+}
+
+fn draw_day9(input: &str, options: &DrawOptions) -> Result<(), CliError> {
+    let day: Day9 = parse_day(9, input)?;
+    if let Some(knot) = options.trail {
+        if knot >= options.knots {
+            return Err(usage(format!(
+                "--trail must be less than the number of knots ({})",
+                options.knots
+            )));
+        }
+        let trails = Trails::record(day.moves(), options.knots);
+        for i in 0..trails.len() {
+            let (min, max) = trails.bounds(i);
+            println!(
+                "Knot {}: {} positions, x {}..={}, y {}..={}",
+                i,
+                trails.count(i),
+                min.x,
+                max.x,
+                min.y,
+                max.y
+            );
+        }
+        println!();
+        print!("{}", trails.render(knot, options.style));
+        return Ok(());
+    }
+
+    let (width, height) = options.viewport;
+    let mut viewport = Viewport::centred(width, height);
+    let mut visited = HashSet::from([Point::ORIGIN]);
+
+    for (step, rope) in day9::trace(day.moves(), options.knots).enumerate() {
+        visited.insert(*rope.last().expect("ropes have at least one knot"));
+        viewport.follow(rope[0], 3);
+        let frame = day9::render_frame(&rope, &visited, &viewport, options.style);
+        if options.dump {
+            println!("== step {} ==\n{}", step + 1, frame);
+        } else {
+            // Move the cursor home and clear the screen before each frame.
+            print!("\x1b[H\x1b[2J{}", frame);
+            io::stdout()
+                .flush()
+                .map_err(|e| Error::new(format!("cannot write frame: {}", e)))?;
+            thread::sleep(options.delay);
+        }
+    }
+    println!("Tail visited {} positions", visited.len());
+    Ok(())
+}
+
+fn draw_day12(input: &str, options: &DrawOptions) -> Result<(), CliError> {
+    let day: Day12 = parse_day(12, input)?;
+    let map = day.map();
+    let route = map
+        .route()
+        .ok_or_else(|| Error::new("no path from S to E").for_day(12))?;
+
+    print!("{}", map.render_route(&route, options.style)?);
+    if let Some(path) = &options.svg {
+        write_file(path, map.route_svg(&route, options.scale))?;
+    }
+    if let Some(path) = &options.ppm {
+        write_file(path, map.route_ppm(&route, options.scale))?;
+    }
+    Ok(())
+}
+
+fn new_day(args: &[String]) -> Result<(), CliError> {
+    let mut title = None;
+    let options = Options::parse(args, &[], |arg, args| {
+        match arg {
+            "-t" | "--title" => title = Some(args.value(arg)?.to_owned()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+
+    // The day needn't be registered yet, so it isn't checked with parse_days.
+    let day = options.days.ok_or_else(|| usage("missing day"))?;
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| usage(format!("invalid day {}", day)))?;
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+    for path in scaffold::generate(Path::new("."), day, &title)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn verify(args: &[String]) -> Result<(), CliError> {
+    let options = Options::parse(args, &[Flag::InputsDir, Flag::Answers], |_, _| Ok(false))?;
+    let config = Config::load()?;
+    let days = options.days()?;
+    let inputs = options.inputs(&config, &days)?;
+    let path = options.answers(&config);
+    let checks = answers::verify(&days, &inputs, &Answers::load(&path)?)?;
+
+    let mut failed = 0;
+    let mut unknown = 0;
+    for check in &checks {
+        let label = format!("Day {} part {}", check.day, check.part.number());
+        let answer = check
+            .answer
+            .as_ref()
+            .map_or_else(String::new, Answer::to_string);
+        match &check.status {
+            Status::Error(e) => {
+                failed += 1;
+                println!("{}: {}", label, check.status);
+                println!("  {}", e.to_string().replace('\n', "\n  "));
+            }
+            Status::Fail(expected) => {
+                failed += 1;
+                println!("{}: {}", label, check.status);
+                println!(
+                    "  expected: {}",
+                    expected.trim_end().replace('\n', "\n    ")
+                );
+                println!("  got:      {}", answer.trim_end().replace('\n', "\n    "));
+            }
+            status => {
+                if *status == Status::Unknown {
+                    unknown += 1;
+                }
+                if answer.contains('\n') {
+                    println!("{}: {}\n{}", label, status, answer.trim_end());
+                } else {
+                    println!("{}: {} {}", label, status, answer);
+                }
+            }
+        }
+    }
+    println!(
+        "{} passed, {} failed, {} unknown",
+        checks.len() - failed - unknown,
+        failed,
+        unknown
+    );
+
+    if failed > 0 {
+        return Err(Error::new(format!(
+            "{} answers failed or did not match {}",
+            failed,
+            path.display()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+fn record(args: &[String]) -> Result<(), CliError> {
+    let mut answer = None;
+    let options = Options::parse(
+        args,
+        &[Flag::Part, Flag::InputsDir, Flag::Answers],
+        |arg, args| {
+            match arg {
+                "--answer" => answer = Some(Answer::from(args.value(arg)?)),
+                _ => return Ok(false),
+            }
+            Ok(true)
+        },
+    )?;
+    let config = Config::load()?;
+    let day = options.day("record")?;
+    let inputs = options.inputs(&config, &[day.number])?;
+    let path = options.answers(&config);
+    let parts = &options.parts;
+    if answer.is_some() && parts.len() != 1 {
+        return Err(usage("--answer requires --part"));
+    }
+
+    let recorded = match answer {
+        Some(answer) => vec![(parts[0], answer)],
+        None => {
+            let solution = day.parse(&inputs.load(day)?)?;
+            parts
+                .iter()
+                .map(|&part| Ok((part, day.solve(solution.as_ref(), part)?)))
+                .collect::<Result<Vec<_>, Error>>()?
+        }
+    };
+
+    let mut answers = Answers::load(&path)?;
+    for (part, answer) in recorded {
+        answers.record(day.number, part, &answer);
+        println!(
+            "Recorded day {} part {}: {}",
+            day.number,
+            part.number(),
+            answer
+        );
+    }
+    Ok(answers.save(&path)?)
+}
+
+fn parse_days(s: &str) -> Result<Vec<u8>, CliError> {
+    runner::parse_days(s).map_err(|e| usage(e.to_string()))
 }
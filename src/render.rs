@@ -0,0 +1,134 @@
+//! Helpers for drawing grids in the terminal and exporting them as images.
+
+use std::fmt::Write;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Terminal rendering: plain characters, or with ANSI 24-bit colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    Ansi,
+}
+
+/// Maps `t` in `0.0..=1.0` from low (dark blue) through green and brown to
+/// high (white), like a relief map.
+pub fn gradient(t: f64) -> Rgb {
+    const STOPS: [(f64, Rgb); 5] = [
+        (0.0, Rgb(20, 40, 120)),
+        (0.3, Rgb(40, 140, 70)),
+        (0.6, Rgb(200, 190, 90)),
+        (0.85, Rgb(130, 90, 60)),
+        (1.0, Rgb(245, 245, 245)),
+    ];
+    let t = t.clamp(0.0, 1.0);
+    let i = STOPS.iter().rposition(|&(at, _)| at <= t).unwrap_or(0);
+    let Some(&(to_at, to)) = STOPS.get(i + 1) else {
+        return STOPS[i].1;
+    };
+    let (from_at, from) = STOPS[i];
+    let f = (t - from_at) / (to_at - from_at);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+/// `text` drawn in `fg` on `bg` using ANSI 24-bit colour escapes.
+pub fn ansi(text: impl std::fmt::Display, fg: Rgb, bg: Rgb) -> String {
+    format!(
+        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m{}\x1b[0m",
+        fg.0, fg.1, fg.2, bg.0, bg.1, bg.2, text
+    )
+}
+
+/// A black or white foreground that stays readable on `bg`.
+pub fn contrast(bg: Rgb) -> Rgb {
+    let luma = 0.299 * bg.0 as f64 + 0.587 * bg.1 as f64 + 0.114 * bg.2 as f64;
+    if luma > 140.0 {
+        Rgb::BLACK
+    } else {
+        Rgb::WHITE
+    }
+}
+
+/// Encodes the grid as a binary PPM (P6) image, each cell `scale` pixels wide.
+pub fn ppm(pixels: &Grid<Rgb>, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (pixels.width() * scale, pixels.height() * scale);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.reserve(width * height * 3);
+    for row in pixels.rows() {
+        for _ in 0..scale {
+            for &Rgb(r, g, b) in row {
+                for _ in 0..scale {
+                    out.extend([r, g, b]);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Starts an SVG document with a rect per cell, each `scale` units wide.
+/// The caller appends any overlay and closes it with `</svg>`.
+pub fn svg(pixels: &Grid<Rgb>, scale: usize) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+        pixels.width() * scale,
+        pixels.height() * scale
+    )
+    .expect("writing to a String cannot fail");
+    for (p, colour) in pixels.iter() {
+        writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="{}"/>"#,
+            p.x as usize * scale,
+            p.y as usize * scale,
+            colour.hex(),
+            s = scale
+        )
+        .expect("writing to a String cannot fail");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gradient, ppm, Rgb};
+    use crate::grid::Grid;
+
+    #[test]
+    fn gradient_ends() {
+        assert_eq!(gradient(0.0), Rgb(20, 40, 120));
+        assert_eq!(gradient(1.0), Rgb(245, 245, 245));
+        assert_eq!(gradient(2.0), gradient(1.0));
+        assert_eq!(gradient(0.15), Rgb(30, 90, 95));
+    }
+
+    #[test]
+    fn ppm_scaled() {
+        let pixels = Grid::from_vec(2, vec![Rgb::BLACK, Rgb::WHITE]).unwrap();
+        let image = ppm(&pixels, 2);
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 4 * 2 * 3);
+        assert_eq!(
+            &image[header.len()..header.len() + 12],
+            [0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use std::env;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::bench;
+use crate::config::Config;
+use crate::days;
+use crate::error::{Error, Result};
+use crate::input::{Inputs, Source};
+use crate::solution::{Answer, Part};
+
+/// Parses a day selection: `7`, `3..=7`, `3-7`, `1,4,9` or `all`. Ranges
+/// include both ends; the days come back sorted, each once.
+pub fn parse_days(s: &str) -> Result<Vec<u8>> {
+    if s == "all" {
+        return Ok(days::DAYS.iter().map(|day| day.number).collect());
+    }
+
+    let mut selected = Vec::new();
+    for item in s.split(',') {
+        if item.contains("..") && !item.contains("..=") {
+            return Err(Error::invalid(
+                item,
+                "ranges include both ends, write them as a..=b or a-b",
+            ));
+        }
+        let range = item.split_once("..=").or_else(|| item.split_once('-'));
+        let (from, to) = match range {
+            Some((from, to)) => (parse_day(from)?, parse_day(to)?),
+            None => (parse_day(item)?, parse_day(item)?),
+        };
+        if from > to {
+            return Err(Error::invalid(item, "invalid day range"));
+        }
+        selected.extend((from..=to).filter(|&day| days::get(day).is_some()));
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    s.trim()
+        .parse()
+        .ok()
+        .filter(|&day| days::get(day).is_some())
+        .ok_or_else(|| Error::invalid(s, "invalid day"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Plain,
+    Json,
+    Table,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            "table" => Ok(Self::Table),
+            _ => Err(Error::invalid(s, "invalid format")),
+        }
+    }
+}
+
+/// The answer to one part of a day, with how long it took to compute.
+/// `parse` is the time spent parsing that day's input, shared by both parts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    #[serde(flatten)]
+    pub answer: Answer,
+    #[serde(rename = "parse_ns", serialize_with = "bench::nanos")]
+    pub parse: Duration,
+    #[serde(rename = "solve_ns", serialize_with = "bench::nanos")]
+    pub solve: Duration,
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.number())
+}
+
+pub fn solve(selected: &[u8], parts: &[Part], inputs: &Inputs) -> Result<Vec<Record>> {
+    let mut records = Vec::new();
+    for &day in selected {
+        let day = days::get(day).ok_or_else(|| Error::new("not implemented").for_day(day))?;
+        let input = inputs.load(day)?;
+        let start = Instant::now();
+        let solution = day.parse(&input)?;
+        let parse = start.elapsed();
+        for &part in parts {
+            let start = Instant::now();
+            let answer = day.solve(solution.as_ref(), part)?;
+            records.push(Record {
+                day: day.number,
+                part,
+                answer,
+                parse,
+                solve: start.elapsed(),
+            });
+        }
+    }
+    Ok(records)
+}
+
+pub fn render(records: &[Record], format: Format) -> Result<String> {
+    let mut out = String::new();
+    match format {
+        Format::Plain => {
+            for record in records {
+                let answer = record.answer.to_string();
+                let label = format!("Day {} part {}", record.day, record.part.number());
+                if answer.contains('\n') {
+                    writeln!(out, "{}:\n{}", label, answer.trim_end())
+                } else {
+                    writeln!(out, "{}: {}", label, answer)
+                }
+                .expect("writing to a String cannot fail");
+            }
+        }
+        Format::Json => {
+            out = serde_json::to_string_pretty(records)
+                .map_err(|e| Error::new(format!("cannot serialize results: {}", e)))?;
+            out.push('\n');
+        }
+        Format::Table => {
+            let rows: Vec<[String; 6]> = records
+                .iter()
+                .map(|record| {
+                    let (kind, answer) = match &record.answer {
+                        Answer::Number(n) => ("number", n.to_string()),
+                        Answer::Text(s) if s.contains('\n') => {
+                            ("text", format!("({} lines)", s.trim_end().lines().count()))
+                        }
+                        Answer::Text(s) => ("text", s.clone()),
+                    };
+                    [
+                        record.day.to_string(),
+                        record.part.number().to_string(),
+                        kind.to_owned(),
+                        answer,
+                        format!("{:.2?}", record.parse),
+                        format!("{:.2?}", record.solve),
+                    ]
+                })
+                .collect();
+            let header = ["day", "part", "type", "answer", "parse", "solve"].map(String::from);
+            let mut widths = header.clone().map(|h| h.len());
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+            for row in std::iter::once(&header).chain(&rows) {
+                let line = format!(
+                    "{:>w0$}  {:>w1$}  {:<w2$}  {:<w3$}  {:>w4$}  {:>w5$}",
+                    row[0],
+                    row[1],
+                    row[2],
+                    row[3],
+                    row[4],
+                    row[5],
+                    w0 = widths[0],
+                    w1 = widths[1],
+                    w2 = widths[2],
+                    w3 = widths[3],
+                    w4 = widths[4],
+                    w5 = widths[5],
+                );
+                writeln!(out, "{}", line.trim_end()).expect("writing to a String cannot fail");
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub fn run(selected: &[u8], parts: &[Part], inputs: &Inputs, format: Format) -> Result<()> {
+    let records = solve(selected, parts, inputs)?;
+    print!("{}", render(&records, format)?);
+    Ok(())
+}
+
+/// Entry point for the standalone `dayN` binaries, which accept an optional
+/// input path (or `-` for stdin) as their only argument.
+pub fn run_standalone(day: u8) {
+    let result = Config::load().and_then(|config| {
+        let mut inputs = Inputs::from_config(&config);
+        if let Some(source) = env::args().nth(1) {
+            inputs = inputs.with_source(Source::from(source.as_str()));
+        }
+        run(&[day], &Part::ALL, &inputs, Format::Plain)
+    });
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render, Format, Record};
+    use crate::solution::Part;
+
+    #[test]
+    fn parse_days() {
+        assert_eq!(super::parse_days("7"), Ok(vec![7]));
+        assert_eq!(super::parse_days("3..=5"), Ok(vec![3, 4, 5]));
+        assert_eq!(super::parse_days("3-5"), Ok(vec![3, 4, 5]));
+        assert_eq!(super::parse_days("1,4..=5"), Ok(vec![1, 4, 5]));
+        assert_eq!(super::parse_days("all").unwrap().len(), crate::DAYS.len());
+        assert_eq!(super::parse_days("1,1"), Ok(vec![1]));
+        assert_eq!(super::parse_days("4-5,1,3..=4"), Ok(vec![1, 3, 4, 5]));
+        assert!(super::parse_days("3..5").is_err());
+        assert!(super::parse_days("0").is_err());
+        assert!(super::parse_days("5..3").is_err());
+        assert!(super::parse_days("x").is_err());
+    }
+
+    #[test]
+    fn render_formats() {
+        let records = [
+            Record {
+                day: 1,
+                part: Part::One,
+                answer: 24000.into(),
+                parse: Duration::from_micros(5),
+                solve: Duration::from_nanos(40),
+            },
+            Record {
+                day: 10,
+                part: Part::Two,
+                answer: "##..\n..##\n".into(),
+                parse: Duration::from_micros(5),
+                solve: Duration::from_micros(2),
+            },
+        ];
+
+        assert_eq!(
+            render(&records, Format::Plain).unwrap(),
+            "Day 1 part 1: 24000\nDay 10 part 2:\n##..\n..##\n"
+        );
+        assert_eq!(
+            render(&records, Format::Table).unwrap(),
+            "day  part  type    answer      parse    solve
+  1     1  number  24000      5.00µs  40.00ns
+ 10     2  text    (2 lines)  5.00µs   2.00µs
+"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&records, Format::Json).unwrap()).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "day": 1,
+                "part": 1,
+                "type": "number",
+                "answer": 24000,
+                "parse_ns": 5000,
+                "solve_ns": 40,
+            })
+        );
+        assert_eq!(json[1]["type"], "text");
+        assert_eq!(json[1]["answer"], "##..\n..##\n");
+    }
+
+    #[test]
+    fn large_numbers() {
+        let records = [Record {
+            day: 11,
+            part: Part::Two,
+            answer: u64::MAX.into(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+        }];
+        assert_eq!(
+            render(&records, Format::Plain).unwrap(),
+            "Day 11 part 2: 18446744073709551615\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&records, Format::Json).unwrap()).unwrap();
+        assert_eq!(json[0]["answer"], u64::MAX);
+    }
+}
@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+const DAY_TEMPLATE: &str = r##"use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day{N} {
+    lines: Vec<String>,
+}
+
+impl Day{N} {
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+impl Solution for Day{N} {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            lines: input.lines().map(str::to_owned).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(Error::new("not implemented"))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(Error::new("not implemented"))
+    }
+}
+
+#[cfg(test)]
+mod day{N}_tests {
+    use super::Day{N};
+    use crate::solution::Solution;
+
+    const INPUT: &str = r#""#;
+
+    #[test]
+    #[ignore = "fill in the example input and answer"]
+    fn part1() {
+        assert_eq!(Day{N}::parse(INPUT).unwrap().part1(), Ok(0.into()));
+    }
+
+    #[test]
+    #[ignore = "fill in the example input and answer"]
+    fn part2() {
+        assert_eq!(Day{N}::parse(INPUT).unwrap().part2(), Ok(0.into()));
+    }
+}
+"##;
+
+const BIN_TEMPLATE: &str = "fn main() {
+    aoc_2022::runner::run_standalone({N});
+}
+";
+
+pub fn day_source(day: u8) -> String {
+    DAY_TEMPLATE.replace("{N}", &day.to_string())
+}
+
+pub fn bin_source(day: u8) -> String {
+    BIN_TEMPLATE.replace("{N}", &day.to_string())
+}
+
+/// Adds `day` to the `pub mod` list and the `DAYS` registry in the source of `days/mod.rs`.
+pub fn register(source: &str, day: u8, title: &str) -> Result<String> {
+    let module = format!("day{}", day);
+    let declaration = format!("pub mod {};", module);
+    if source.lines().any(|line| line == declaration) {
+        return Err(Error::new(format!("{} is already registered", module)));
+    }
+
+    let mut lines: Vec<String> = source.lines().map(str::to_owned).collect();
+
+    // Module declarations are kept in rustfmt's order, sorted by name.
+    let modules: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line.strip_prefix("pub mod ")?.strip_suffix(';')?)))
+        .filter(|(_, name)| name.starts_with("day"))
+        .collect();
+    let last = modules
+        .last()
+        .ok_or_else(|| Error::new("no day modules found"))?
+        .0;
+    let at = modules
+        .iter()
+        .find(|(_, name)| *name > module.as_str())
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, declaration);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS: [Day; "))
+        .ok_or_else(|| Error::new("DAYS registry not found"))?;
+    let count: usize = lines[start]
+        .trim_start_matches("pub static DAYS: [Day; ")
+        .split(']')
+        .next()
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| Error::new("cannot read the size of DAYS"))?;
+    lines[start] = lines[start].replacen(&count.to_string(), &(count + 1).to_string(), 1);
+
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| Error::new("end of DAYS registry not found"))?;
+    let at = (start + 1..end)
+        .find(|&i| registered_day(&lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(end);
+    lines.insert(
+        at,
+        format!("    day!({}, {}::Day{}, {:?}),", day, module, day, title),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn registered_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("day!(")?
+        .split(',')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Creates the solution, binary and empty input for `day` under the crate at
+/// `root` and registers it, returning the files written.
+pub fn generate(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    if day == 0 || day > 25 {
+        return Err(Error::new(format!("invalid day {}", day)));
+    }
+    let mod_rs = root.join("src/days/mod.rs");
+    let files = [
+        (
+            root.join(format!("src/days/day{}.rs", day)),
+            day_source(day),
+        ),
+        (root.join(format!("src/bin/day{}.rs", day)), bin_source(day)),
+        (
+            root.join(format!("src/inputs/day{}.txt", day)),
+            String::new(),
+        ),
+    ];
+    for (path, _) in &files {
+        if path.exists() {
+            return Err(Error::new(format!("{} already exists", path.display())));
+        }
+    }
+
+    let source = read(&mod_rs)?;
+    let registered = register(&source, day, title)?;
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        write(&path, &contents)?;
+        written.push(path);
+    }
+    write(&mod_rs, &registered)?;
+    written.push(mod_rs);
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("cannot read {}: {}", path.display(), e)))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents)
+        .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{generate, register};
+    use crate::testing::TempDir;
+
+    const MOD_RS: &str = include_str!("days/mod.rs");
+
+    #[test]
+    fn register_day() {
+        let registered = register(MOD_RS, 25, "Full of Hot Air").unwrap();
+        assert!(registered.contains("pub mod day2;\npub mod day25;\npub mod day3;\n"));
+        assert!(registered.contains(&format!("[Day; {}]", crate::DAYS.len() + 1)));
+        assert!(registered.contains("    day!(25, day25::Day25, \"Full of Hot Air\"),\n];"));
+        assert!(register(&registered, 25, "Again").is_err());
+    }
+
+    #[test]
+    fn generate_day() {
+        let root = TempDir::new("scaffold");
+        for dir in ["src/days", "src/bin", "src/inputs"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/days/mod.rs"), MOD_RS).unwrap();
+
+        let written = generate(&root, 25, "Day 25").unwrap();
+        assert_eq!(written.len(), 4);
+        let source = fs::read_to_string(root.join("src/days/day25.rs")).unwrap();
+        assert!(source.contains("pub struct Day25 {"));
+        assert!(source.contains("mod day25_tests {"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/inputs/day25.txt")).unwrap(),
+            ""
+        );
+        assert!(generate(&root, 25, "Day 25").is_err());
+    }
+}
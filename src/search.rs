@@ -0,0 +1,251 @@
+//! Graph searches over an implicit graph described by a neighbour function.
+//!
+//! Every search takes one or more start nodes, a function returning the
+//! neighbours of a node, and a goal predicate. The search stops as soon as a
+//! goal is reached (pass `|_| false` to explore everything reachable) and
+//! returns a [`Search`] holding the distance to and predecessor of every node
+//! it settled, from which paths can be rebuilt.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge weights: anything totally ordered that can be summed from a zero default.
+/// Path costs are plain sums, so wrap weights that could overflow in
+/// [`Saturating`](std::num::Saturating).
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+#[derive(Debug, Clone)]
+pub struct Search<N, C = usize> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Search<N, C> {
+    /// The goal that stopped the search, if one was reached.
+    pub fn goal(&self) -> Option<N> {
+        self.goal
+    }
+
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.distances.get(&node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn predecessor(&self, node: N) -> Option<N> {
+        self.predecessors.get(&node).copied()
+    }
+
+    /// The path from the nearest start to `node`, both included.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        self.distances.get(&node)?;
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(&previous) = self.predecessors.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal that stopped the search.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal?)
+    }
+}
+
+/// Breadth-first search over unweighted edges; distances count steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node] + 1;
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next) {
+                entry.insert(distance);
+                search.predecessors.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm; `neighbours` returns each neighbour with the cost to reach it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    is_goal: impl FnMut(N) -> bool,
+) -> Search<N, C>
+where
+    N: Copy + Ord + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a
+/// goal, or the paths found may not be the shortest. It needn't be consistent:
+/// a node is expanded again whenever a cheaper way to it turns up, so the
+/// path to the goal is always the cheapest.
+///
+/// With an inconsistent heuristic, distances to nodes off that path may
+/// still be more than the shortest when the search stops at the goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> C,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Search<N, C>
+where
+    N: Copy + Ord + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut best = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut expanded = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        best.insert(start, C::default());
+        queue.push(Reverse((heuristic(start), C::default(), start)));
+    }
+
+    let mut goal = None;
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        // Skip entries superseded by a cheaper way to the same node.
+        if best.get(&node).is_some_and(|&b| cost > b) {
+            continue;
+        }
+        expanded.insert(node);
+        if is_goal(node) {
+            goal = Some(node);
+            break;
+        }
+        for (next, step) in neighbours(node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_some_and(|&b| b <= next_cost) {
+                continue;
+            }
+            best.insert(next, next_cost);
+            predecessors.insert(next, node);
+            queue.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+        }
+    }
+
+    // Nodes still waiting in the queue only have tentative distances; keep
+    // just the expanded ones so paths are only rebuilt from those.
+    best.retain(|node, _| expanded.contains(node));
+    predecessors.retain(|node, _| expanded.contains(node));
+    Search {
+        distances: best,
+        predecessors,
+        goal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+
+    // A line of nodes 0..10 where each node links to its neighbours, plus a
+    // shortcut 0 -> 7 that costs 10 in the weighted version.
+    fn line(n: i32) -> Vec<i32> {
+        let mut next = vec![n - 1, n + 1];
+        if n == 0 {
+            next.push(7);
+        }
+        next.into_iter().filter(|n| (0..10).contains(n)).collect()
+    }
+
+    fn weighted(n: i32) -> Vec<(i32, u32)> {
+        line(n)
+            .into_iter()
+            .map(|m| (m, if (n - m).abs() > 1 { 10 } else { 1 }))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_paths() {
+        let search = bfs([0], line, |n| n == 8);
+        assert_eq!(search.goal(), Some(8));
+        assert_eq!(search.goal_path(), Some(vec![0, 7, 8]));
+        assert_eq!(search.distance(8), Some(2));
+
+        let search = bfs([0], line, |_| false);
+        assert_eq!(search.distances().len(), 10);
+        assert_eq!(search.goal(), None);
+
+        let search = bfs([9, 3], line, |n| n == 5);
+        assert_eq!(search.goal_path(), Some(vec![3, 4, 5]));
+    }
+
+    #[test]
+    fn weighted_paths() {
+        let search = dijkstra([0], weighted, |n| n == 8);
+        assert_eq!(search.distance(8), Some(8));
+        assert_eq!(search.goal_path(), Some((0..=8).collect()));
+
+        let search = dijkstra([0], weighted, |_| false);
+        assert_eq!(search.distance(9), Some(9));
+        assert_eq!(search.predecessor(7), Some(6));
+
+        let search = astar([0], weighted, |n| 8u32.abs_diff(n as u32), |n| n == 8);
+        assert_eq!(search.distance(8), Some(8));
+        assert_eq!(search.path(8), Some((0..=8).collect()));
+        assert_eq!(search.path(42), None);
+    }
+
+    #[test]
+    fn inconsistent_heuristic() {
+        // h(A) = 3 never overestimates A's true remaining cost of 3, but it is
+        // inconsistent: C is first expanded the expensive way, via B.
+        let graph = |n: char| match n {
+            'S' => vec![('A', 1), ('B', 1)],
+            'A' => vec![('C', 1)],
+            'B' => vec![('C', 2)],
+            'C' => vec![('G', 2)],
+            _ => vec![],
+        };
+        let heuristic = |n: char| if n == 'A' { 3 } else { 0 };
+
+        let expected = dijkstra(['S'], graph, |n| n == 'G');
+        assert_eq!(expected.distance('G'), Some(4u32));
+        let search = astar(['S'], graph, heuristic, |n| n == 'G');
+        assert_eq!(search.distance('G'), Some(4));
+        assert_eq!(search.goal_path(), Some(vec!['S', 'A', 'C', 'G']));
+        assert_eq!(search.goal_path(), expected.goal_path());
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(Error::invalid(s, "invalid part")),
+        }
+    }
+}
+
+/// Numbers are kept as `i128` so every integer type converts without wrapping.
+/// Serializes as `{"type": "number", "answer": 42}` or `{"type": "text", "answer": "..."}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "answer", rename_all = "lowercase")]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Number(n.into())
+            }
+        })*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64);
+
+// `i128` has no `From<usize>` since usize could in principle be wider; it's at
+// most 64 bits on every target Rust supports.
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Number(n as u64 as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_owned())
+    }
+}
+
+/// A day's puzzle: parsed once from the input, then queried for either part.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    fn solve(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+pub fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>> {
+    match S::parse(input) {
+        Ok(solution) => Ok(Box::new(solution)),
+        Err(e) => Err(e.locate(input)),
+    }
+}
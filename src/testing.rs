@@ -0,0 +1,35 @@
+//! Fixtures shared by the unit tests.
+
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// A fresh directory under the system temp dir, removed again on drop so a
+/// failing test doesn't leave it behind.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// `name` keeps tests running in parallel out of each other's way.
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("aoc_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}